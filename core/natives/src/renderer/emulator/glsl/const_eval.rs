@@ -1,8 +1,10 @@
 use std::collections::HashMap;
 use std::hash::Hash;
+use std::rc::Rc;
 
-use glsl::syntax::{ArraySpecifier, ArraySpecifierDimension, BinaryOp, Declaration, Expr, ExternalDeclaration, FunIdentifier, Identifier, Initializer, NonEmpty, ShaderStage, StructSpecifier, TranslationUnit, TypeSpecifier, TypeSpecifierNonArray, UnaryOp};
-use glsl::visitor::{Visit, VisitorMut};
+use glsl::syntax::{ArraySpecifier, ArraySpecifierDimension, BinaryOp, CompoundStatement, Declaration, Expr, ExternalDeclaration, FunIdentifier, Identifier, InitDeclaratorList, Initializer, NonEmpty, ShaderStage, SimpleStatement, StorageQualifier, StructSpecifier, TranslationUnit, TypeQualifierSpec, TypeSpecifier, TypeSpecifierNonArray, UnaryOp};
+use glsl::visitor::{HostMut, Visit, VisitorMut};
+use half::f16;
 use nalgebra::{Matrix2, Matrix2x3, Matrix2x4, Matrix3, Matrix3x2, Matrix3x4, Matrix4, Matrix4x2, Matrix4x3, Scalar, Vector2, Vector3, Vector4};
 
 use paste::paste;
@@ -684,6 +686,13 @@ pub enum ConstBaseVal {
     UInt(ConstSVVal<u32>),
     Float(ConstSVMVal<f32>),
     Double(ConstSVMVal<f64>),
+    Int8(ConstSVVal<i8>),
+    Int16(ConstSVVal<i16>),
+    Int64(ConstSVVal<i64>),
+    UInt8(ConstSVVal<u8>),
+    UInt16(ConstSVVal<u16>),
+    UInt64(ConstSVVal<u64>),
+    Float16(ConstSVMVal<f16>),
 }
 
 impl ConstBaseVal {
@@ -694,6 +703,14 @@ impl ConstBaseVal {
     impl_const_base_val_new_m!(Float, f32);
     impl_const_base_val_new_sv!(Double, f64, double, d);
     impl_const_base_val_new_m!(Double, f64, d);
+    impl_const_base_val_new_sv!(Int8, i8, int8, i8);
+    impl_const_base_val_new_sv!(Int16, i16, int16, i16);
+    impl_const_base_val_new_sv!(Int64, i64, int64, i64);
+    impl_const_base_val_new_sv!(UInt8, u8, uint8, u8);
+    impl_const_base_val_new_sv!(UInt16, u16, uint16, u16);
+    impl_const_base_val_new_sv!(UInt64, u64, uint64, u64);
+    impl_const_base_val_new_sv!(Float16, f16, float16, f16);
+    impl_const_base_val_new_m!(Float16, f16, f16);
 
     fn test() {
         Self::new_bool(true);
@@ -707,6 +724,13 @@ impl ConstBaseVal {
             ConstBaseVal::UInt(v) => v.get_shape(),
             ConstBaseVal::Float(v) => v.get_shape(),
             ConstBaseVal::Double(v) => v.get_shape(),
+            ConstBaseVal::Int8(v) => v.get_shape(),
+            ConstBaseVal::Int16(v) => v.get_shape(),
+            ConstBaseVal::Int64(v) => v.get_shape(),
+            ConstBaseVal::UInt8(v) => v.get_shape(),
+            ConstBaseVal::UInt16(v) => v.get_shape(),
+            ConstBaseVal::UInt64(v) => v.get_shape(),
+            ConstBaseVal::Float16(v) => v.get_shape(),
         }
     }
 
@@ -754,6 +778,43 @@ impl ConstBaseVal {
             Self::Double(ConstSVMVal::Matrix(ConstMVal::Mat42(_))) => TypeSpecifierNonArray::DMat42,
             Self::Double(ConstSVMVal::Matrix(ConstMVal::Mat43(_))) => TypeSpecifierNonArray::DMat43,
             Self::Double(ConstSVMVal::Matrix(ConstMVal::Mat4(_))) => TypeSpecifierNonArray::DMat4,
+            Self::Int8(ConstSVVal::Scalar(_)) => TypeSpecifierNonArray::Int8,
+            Self::Int8(ConstSVVal::Vector(ConstVVal::Vec2(_))) => TypeSpecifierNonArray::I8Vec2,
+            Self::Int8(ConstSVVal::Vector(ConstVVal::Vec3(_))) => TypeSpecifierNonArray::I8Vec3,
+            Self::Int8(ConstSVVal::Vector(ConstVVal::Vec4(_))) => TypeSpecifierNonArray::I8Vec4,
+            Self::Int16(ConstSVVal::Scalar(_)) => TypeSpecifierNonArray::Int16,
+            Self::Int16(ConstSVVal::Vector(ConstVVal::Vec2(_))) => TypeSpecifierNonArray::I16Vec2,
+            Self::Int16(ConstSVVal::Vector(ConstVVal::Vec3(_))) => TypeSpecifierNonArray::I16Vec3,
+            Self::Int16(ConstSVVal::Vector(ConstVVal::Vec4(_))) => TypeSpecifierNonArray::I16Vec4,
+            Self::Int64(ConstSVVal::Scalar(_)) => TypeSpecifierNonArray::Int64,
+            Self::Int64(ConstSVVal::Vector(ConstVVal::Vec2(_))) => TypeSpecifierNonArray::I64Vec2,
+            Self::Int64(ConstSVVal::Vector(ConstVVal::Vec3(_))) => TypeSpecifierNonArray::I64Vec3,
+            Self::Int64(ConstSVVal::Vector(ConstVVal::Vec4(_))) => TypeSpecifierNonArray::I64Vec4,
+            Self::UInt8(ConstSVVal::Scalar(_)) => TypeSpecifierNonArray::UInt8,
+            Self::UInt8(ConstSVVal::Vector(ConstVVal::Vec2(_))) => TypeSpecifierNonArray::U8Vec2,
+            Self::UInt8(ConstSVVal::Vector(ConstVVal::Vec3(_))) => TypeSpecifierNonArray::U8Vec3,
+            Self::UInt8(ConstSVVal::Vector(ConstVVal::Vec4(_))) => TypeSpecifierNonArray::U8Vec4,
+            Self::UInt16(ConstSVVal::Scalar(_)) => TypeSpecifierNonArray::UInt16,
+            Self::UInt16(ConstSVVal::Vector(ConstVVal::Vec2(_))) => TypeSpecifierNonArray::U16Vec2,
+            Self::UInt16(ConstSVVal::Vector(ConstVVal::Vec3(_))) => TypeSpecifierNonArray::U16Vec3,
+            Self::UInt16(ConstSVVal::Vector(ConstVVal::Vec4(_))) => TypeSpecifierNonArray::U16Vec4,
+            Self::UInt64(ConstSVVal::Scalar(_)) => TypeSpecifierNonArray::UInt64,
+            Self::UInt64(ConstSVVal::Vector(ConstVVal::Vec2(_))) => TypeSpecifierNonArray::U64Vec2,
+            Self::UInt64(ConstSVVal::Vector(ConstVVal::Vec3(_))) => TypeSpecifierNonArray::U64Vec3,
+            Self::UInt64(ConstSVVal::Vector(ConstVVal::Vec4(_))) => TypeSpecifierNonArray::U64Vec4,
+            Self::Float16(ConstSVMVal::Scalar(_)) => TypeSpecifierNonArray::Float16,
+            Self::Float16(ConstSVMVal::Vector(ConstVVal::Vec2(_))) => TypeSpecifierNonArray::F16Vec2,
+            Self::Float16(ConstSVMVal::Vector(ConstVVal::Vec3(_))) => TypeSpecifierNonArray::F16Vec3,
+            Self::Float16(ConstSVMVal::Vector(ConstVVal::Vec4(_))) => TypeSpecifierNonArray::F16Vec4,
+            Self::Float16(ConstSVMVal::Matrix(ConstMVal::Mat2(_))) => TypeSpecifierNonArray::F16Mat2,
+            Self::Float16(ConstSVMVal::Matrix(ConstMVal::Mat23(_))) => TypeSpecifierNonArray::F16Mat23,
+            Self::Float16(ConstSVMVal::Matrix(ConstMVal::Mat24(_))) => TypeSpecifierNonArray::F16Mat24,
+            Self::Float16(ConstSVMVal::Matrix(ConstMVal::Mat32(_))) => TypeSpecifierNonArray::F16Mat32,
+            Self::Float16(ConstSVMVal::Matrix(ConstMVal::Mat3(_))) => TypeSpecifierNonArray::F16Mat3,
+            Self::Float16(ConstSVMVal::Matrix(ConstMVal::Mat34(_))) => TypeSpecifierNonArray::F16Mat34,
+            Self::Float16(ConstSVMVal::Matrix(ConstMVal::Mat42(_))) => TypeSpecifierNonArray::F16Mat42,
+            Self::Float16(ConstSVMVal::Matrix(ConstMVal::Mat43(_))) => TypeSpecifierNonArray::F16Mat43,
+            Self::Float16(ConstSVMVal::Matrix(ConstMVal::Mat4(_))) => TypeSpecifierNonArray::F16Mat4,
         }
     }
 
@@ -830,6 +891,80 @@ impl ConstBaseVal {
                 Expr::FunCall(FunIdentifier::Identifier(Identifier::from("dmat43")), v.iter().cloned().map(Expr::DoubleConst).collect()),
             Self::Double(ConstSVMVal::Matrix(ConstMVal::Mat4(v))) =>
                 Expr::FunCall(FunIdentifier::Identifier(Identifier::from("dmat4")), v.iter().cloned().map(Expr::DoubleConst).collect()),
+            Self::Int8(ConstSVVal::Scalar(v)) =>
+                Expr::FunCall(FunIdentifier::Identifier(Identifier::from("int8_t")), vec![Expr::IntConst(*v as i32)]),
+            Self::Int8(ConstSVVal::Vector(ConstVVal::Vec2(v))) =>
+                Expr::FunCall(FunIdentifier::Identifier(Identifier::from("i8vec2")), v.iter().map(|v| Expr::IntConst(*v as i32)).collect()),
+            Self::Int8(ConstSVVal::Vector(ConstVVal::Vec3(v))) =>
+                Expr::FunCall(FunIdentifier::Identifier(Identifier::from("i8vec3")), v.iter().map(|v| Expr::IntConst(*v as i32)).collect()),
+            Self::Int8(ConstSVVal::Vector(ConstVVal::Vec4(v))) =>
+                Expr::FunCall(FunIdentifier::Identifier(Identifier::from("i8vec4")), v.iter().map(|v| Expr::IntConst(*v as i32)).collect()),
+            Self::Int16(ConstSVVal::Scalar(v)) =>
+                Expr::FunCall(FunIdentifier::Identifier(Identifier::from("int16_t")), vec![Expr::IntConst(*v as i32)]),
+            Self::Int16(ConstSVVal::Vector(ConstVVal::Vec2(v))) =>
+                Expr::FunCall(FunIdentifier::Identifier(Identifier::from("i16vec2")), v.iter().map(|v| Expr::IntConst(*v as i32)).collect()),
+            Self::Int16(ConstSVVal::Vector(ConstVVal::Vec3(v))) =>
+                Expr::FunCall(FunIdentifier::Identifier(Identifier::from("i16vec3")), v.iter().map(|v| Expr::IntConst(*v as i32)).collect()),
+            Self::Int16(ConstSVVal::Vector(ConstVVal::Vec4(v))) =>
+                Expr::FunCall(FunIdentifier::Identifier(Identifier::from("i16vec4")), v.iter().map(|v| Expr::IntConst(*v as i32)).collect()),
+            Self::Int64(ConstSVVal::Scalar(v)) =>
+                Expr::FunCall(FunIdentifier::Identifier(Identifier::from("int64_t")), vec![Expr::DoubleConst(*v as f64)]),
+            Self::Int64(ConstSVVal::Vector(ConstVVal::Vec2(v))) =>
+                Expr::FunCall(FunIdentifier::Identifier(Identifier::from("i64vec2")), v.iter().map(|v| Expr::DoubleConst(*v as f64)).collect()),
+            Self::Int64(ConstSVVal::Vector(ConstVVal::Vec3(v))) =>
+                Expr::FunCall(FunIdentifier::Identifier(Identifier::from("i64vec3")), v.iter().map(|v| Expr::DoubleConst(*v as f64)).collect()),
+            Self::Int64(ConstSVVal::Vector(ConstVVal::Vec4(v))) =>
+                Expr::FunCall(FunIdentifier::Identifier(Identifier::from("i64vec4")), v.iter().map(|v| Expr::DoubleConst(*v as f64)).collect()),
+            Self::UInt8(ConstSVVal::Scalar(v)) =>
+                Expr::FunCall(FunIdentifier::Identifier(Identifier::from("uint8_t")), vec![Expr::UIntConst(*v as u32)]),
+            Self::UInt8(ConstSVVal::Vector(ConstVVal::Vec2(v))) =>
+                Expr::FunCall(FunIdentifier::Identifier(Identifier::from("u8vec2")), v.iter().map(|v| Expr::UIntConst(*v as u32)).collect()),
+            Self::UInt8(ConstSVVal::Vector(ConstVVal::Vec3(v))) =>
+                Expr::FunCall(FunIdentifier::Identifier(Identifier::from("u8vec3")), v.iter().map(|v| Expr::UIntConst(*v as u32)).collect()),
+            Self::UInt8(ConstSVVal::Vector(ConstVVal::Vec4(v))) =>
+                Expr::FunCall(FunIdentifier::Identifier(Identifier::from("u8vec4")), v.iter().map(|v| Expr::UIntConst(*v as u32)).collect()),
+            Self::UInt16(ConstSVVal::Scalar(v)) =>
+                Expr::FunCall(FunIdentifier::Identifier(Identifier::from("uint16_t")), vec![Expr::UIntConst(*v as u32)]),
+            Self::UInt16(ConstSVVal::Vector(ConstVVal::Vec2(v))) =>
+                Expr::FunCall(FunIdentifier::Identifier(Identifier::from("u16vec2")), v.iter().map(|v| Expr::UIntConst(*v as u32)).collect()),
+            Self::UInt16(ConstSVVal::Vector(ConstVVal::Vec3(v))) =>
+                Expr::FunCall(FunIdentifier::Identifier(Identifier::from("u16vec3")), v.iter().map(|v| Expr::UIntConst(*v as u32)).collect()),
+            Self::UInt16(ConstSVVal::Vector(ConstVVal::Vec4(v))) =>
+                Expr::FunCall(FunIdentifier::Identifier(Identifier::from("u16vec4")), v.iter().map(|v| Expr::UIntConst(*v as u32)).collect()),
+            Self::UInt64(ConstSVVal::Scalar(v)) =>
+                Expr::FunCall(FunIdentifier::Identifier(Identifier::from("uint64_t")), vec![Expr::DoubleConst(*v as f64)]),
+            Self::UInt64(ConstSVVal::Vector(ConstVVal::Vec2(v))) =>
+                Expr::FunCall(FunIdentifier::Identifier(Identifier::from("u64vec2")), v.iter().map(|v| Expr::DoubleConst(*v as f64)).collect()),
+            Self::UInt64(ConstSVVal::Vector(ConstVVal::Vec3(v))) =>
+                Expr::FunCall(FunIdentifier::Identifier(Identifier::from("u64vec3")), v.iter().map(|v| Expr::DoubleConst(*v as f64)).collect()),
+            Self::UInt64(ConstSVVal::Vector(ConstVVal::Vec4(v))) =>
+                Expr::FunCall(FunIdentifier::Identifier(Identifier::from("u64vec4")), v.iter().map(|v| Expr::DoubleConst(*v as f64)).collect()),
+            Self::Float16(ConstSVMVal::Scalar(v)) =>
+                Expr::FunCall(FunIdentifier::Identifier(Identifier::from("float16_t")), vec![Expr::FloatConst(v.to_f32())]),
+            Self::Float16(ConstSVMVal::Vector(ConstVVal::Vec2(v))) =>
+                Expr::FunCall(FunIdentifier::Identifier(Identifier::from("f16vec2")), v.iter().map(|v| Expr::FloatConst(v.to_f32())).collect()),
+            Self::Float16(ConstSVMVal::Vector(ConstVVal::Vec3(v))) =>
+                Expr::FunCall(FunIdentifier::Identifier(Identifier::from("f16vec3")), v.iter().map(|v| Expr::FloatConst(v.to_f32())).collect()),
+            Self::Float16(ConstSVMVal::Vector(ConstVVal::Vec4(v))) =>
+                Expr::FunCall(FunIdentifier::Identifier(Identifier::from("f16vec4")), v.iter().map(|v| Expr::FloatConst(v.to_f32())).collect()),
+            Self::Float16(ConstSVMVal::Matrix(ConstMVal::Mat2(v))) =>
+                Expr::FunCall(FunIdentifier::Identifier(Identifier::from("f16mat2")), v.iter().map(|v| Expr::FloatConst(v.to_f32())).collect()),
+            Self::Float16(ConstSVMVal::Matrix(ConstMVal::Mat23(v))) =>
+                Expr::FunCall(FunIdentifier::Identifier(Identifier::from("f16mat23")), v.iter().map(|v| Expr::FloatConst(v.to_f32())).collect()),
+            Self::Float16(ConstSVMVal::Matrix(ConstMVal::Mat24(v))) =>
+                Expr::FunCall(FunIdentifier::Identifier(Identifier::from("f16mat24")), v.iter().map(|v| Expr::FloatConst(v.to_f32())).collect()),
+            Self::Float16(ConstSVMVal::Matrix(ConstMVal::Mat32(v))) =>
+                Expr::FunCall(FunIdentifier::Identifier(Identifier::from("f16mat32")), v.iter().map(|v| Expr::FloatConst(v.to_f32())).collect()),
+            Self::Float16(ConstSVMVal::Matrix(ConstMVal::Mat3(v))) =>
+                Expr::FunCall(FunIdentifier::Identifier(Identifier::from("f16mat3")), v.iter().map(|v| Expr::FloatConst(v.to_f32())).collect()),
+            Self::Float16(ConstSVMVal::Matrix(ConstMVal::Mat34(v))) =>
+                Expr::FunCall(FunIdentifier::Identifier(Identifier::from("f16mat34")), v.iter().map(|v| Expr::FloatConst(v.to_f32())).collect()),
+            Self::Float16(ConstSVMVal::Matrix(ConstMVal::Mat42(v))) =>
+                Expr::FunCall(FunIdentifier::Identifier(Identifier::from("f16mat42")), v.iter().map(|v| Expr::FloatConst(v.to_f32())).collect()),
+            Self::Float16(ConstSVMVal::Matrix(ConstMVal::Mat43(v))) =>
+                Expr::FunCall(FunIdentifier::Identifier(Identifier::from("f16mat43")), v.iter().map(|v| Expr::FloatConst(v.to_f32())).collect()),
+            Self::Float16(ConstSVMVal::Matrix(ConstMVal::Mat4(v))) =>
+                Expr::FunCall(FunIdentifier::Identifier(Identifier::from("f16mat4")), v.iter().map(|v| Expr::FloatConst(v.to_f32())).collect()),
         }
     }
 }
@@ -841,6 +976,100 @@ impl_from_to_const_base_val_sv!(f32, Float);
 impl_from_to_const_base_val_m!(f32, Float);
 impl_from_to_const_base_val_sv!(f64, Double);
 impl_from_to_const_base_val_m!(f64, Double);
+impl_from_to_const_base_val_sv!(i8, Int8);
+impl_from_to_const_base_val_sv!(i16, Int16);
+impl_from_to_const_base_val_sv!(i64, Int64);
+impl_from_to_const_base_val_sv!(u8, UInt8);
+impl_from_to_const_base_val_sv!(u16, UInt16);
+impl_from_to_const_base_val_sv!(u64, UInt64);
+impl_from_to_const_base_val_sv!(f16, Float16);
+impl_from_to_const_base_val_m!(f16, Float16);
+
+/// The bare GLSL keyword for a [`TypeSpecifierNonArray`], used to build the callee of a
+/// constructor-call [`Expr`] (e.g. the `float` in `float[3](...)`).
+fn type_specifier_non_array_name(ty: &TypeSpecifierNonArray) -> String {
+    match ty {
+        TypeSpecifierNonArray::Void => "void".to_string(),
+        TypeSpecifierNonArray::Bool => "bool".to_string(),
+        TypeSpecifierNonArray::Int => "int".to_string(),
+        TypeSpecifierNonArray::UInt => "uint".to_string(),
+        TypeSpecifierNonArray::Float => "float".to_string(),
+        TypeSpecifierNonArray::Double => "double".to_string(),
+        TypeSpecifierNonArray::Vec2 => "vec2".to_string(),
+        TypeSpecifierNonArray::Vec3 => "vec3".to_string(),
+        TypeSpecifierNonArray::Vec4 => "vec4".to_string(),
+        TypeSpecifierNonArray::DVec2 => "dvec2".to_string(),
+        TypeSpecifierNonArray::DVec3 => "dvec3".to_string(),
+        TypeSpecifierNonArray::DVec4 => "dvec4".to_string(),
+        TypeSpecifierNonArray::BVec2 => "bvec2".to_string(),
+        TypeSpecifierNonArray::BVec3 => "bvec3".to_string(),
+        TypeSpecifierNonArray::BVec4 => "bvec4".to_string(),
+        TypeSpecifierNonArray::IVec2 => "ivec2".to_string(),
+        TypeSpecifierNonArray::IVec3 => "ivec3".to_string(),
+        TypeSpecifierNonArray::IVec4 => "ivec4".to_string(),
+        TypeSpecifierNonArray::UVec2 => "uvec2".to_string(),
+        TypeSpecifierNonArray::UVec3 => "uvec3".to_string(),
+        TypeSpecifierNonArray::UVec4 => "uvec4".to_string(),
+        TypeSpecifierNonArray::Mat2 => "mat2".to_string(),
+        TypeSpecifierNonArray::Mat3 => "mat3".to_string(),
+        TypeSpecifierNonArray::Mat4 => "mat4".to_string(),
+        TypeSpecifierNonArray::Mat23 => "mat23".to_string(),
+        TypeSpecifierNonArray::Mat24 => "mat24".to_string(),
+        TypeSpecifierNonArray::Mat32 => "mat32".to_string(),
+        TypeSpecifierNonArray::Mat34 => "mat34".to_string(),
+        TypeSpecifierNonArray::Mat42 => "mat42".to_string(),
+        TypeSpecifierNonArray::Mat43 => "mat43".to_string(),
+        TypeSpecifierNonArray::DMat2 => "dmat2".to_string(),
+        TypeSpecifierNonArray::DMat3 => "dmat3".to_string(),
+        TypeSpecifierNonArray::DMat4 => "dmat4".to_string(),
+        TypeSpecifierNonArray::DMat23 => "dmat23".to_string(),
+        TypeSpecifierNonArray::DMat24 => "dmat24".to_string(),
+        TypeSpecifierNonArray::DMat32 => "dmat32".to_string(),
+        TypeSpecifierNonArray::DMat34 => "dmat34".to_string(),
+        TypeSpecifierNonArray::DMat42 => "dmat42".to_string(),
+        TypeSpecifierNonArray::DMat43 => "dmat43".to_string(),
+        TypeSpecifierNonArray::Int8 => "int8_t".to_string(),
+        TypeSpecifierNonArray::Int16 => "int16_t".to_string(),
+        TypeSpecifierNonArray::Int64 => "int64_t".to_string(),
+        TypeSpecifierNonArray::UInt8 => "uint8_t".to_string(),
+        TypeSpecifierNonArray::UInt16 => "uint16_t".to_string(),
+        TypeSpecifierNonArray::UInt64 => "uint64_t".to_string(),
+        TypeSpecifierNonArray::Float16 => "float16_t".to_string(),
+        TypeSpecifierNonArray::I8Vec2 => "i8vec2".to_string(),
+        TypeSpecifierNonArray::I8Vec3 => "i8vec3".to_string(),
+        TypeSpecifierNonArray::I8Vec4 => "i8vec4".to_string(),
+        TypeSpecifierNonArray::I16Vec2 => "i16vec2".to_string(),
+        TypeSpecifierNonArray::I16Vec3 => "i16vec3".to_string(),
+        TypeSpecifierNonArray::I16Vec4 => "i16vec4".to_string(),
+        TypeSpecifierNonArray::I64Vec2 => "i64vec2".to_string(),
+        TypeSpecifierNonArray::I64Vec3 => "i64vec3".to_string(),
+        TypeSpecifierNonArray::I64Vec4 => "i64vec4".to_string(),
+        TypeSpecifierNonArray::U8Vec2 => "u8vec2".to_string(),
+        TypeSpecifierNonArray::U8Vec3 => "u8vec3".to_string(),
+        TypeSpecifierNonArray::U8Vec4 => "u8vec4".to_string(),
+        TypeSpecifierNonArray::U16Vec2 => "u16vec2".to_string(),
+        TypeSpecifierNonArray::U16Vec3 => "u16vec3".to_string(),
+        TypeSpecifierNonArray::U16Vec4 => "u16vec4".to_string(),
+        TypeSpecifierNonArray::U64Vec2 => "u64vec2".to_string(),
+        TypeSpecifierNonArray::U64Vec3 => "u64vec3".to_string(),
+        TypeSpecifierNonArray::U64Vec4 => "u64vec4".to_string(),
+        TypeSpecifierNonArray::F16Vec2 => "f16vec2".to_string(),
+        TypeSpecifierNonArray::F16Vec3 => "f16vec3".to_string(),
+        TypeSpecifierNonArray::F16Vec4 => "f16vec4".to_string(),
+        TypeSpecifierNonArray::F16Mat2 => "f16mat2".to_string(),
+        TypeSpecifierNonArray::F16Mat23 => "f16mat23".to_string(),
+        TypeSpecifierNonArray::F16Mat24 => "f16mat24".to_string(),
+        TypeSpecifierNonArray::F16Mat32 => "f16mat32".to_string(),
+        TypeSpecifierNonArray::F16Mat3 => "f16mat3".to_string(),
+        TypeSpecifierNonArray::F16Mat34 => "f16mat34".to_string(),
+        TypeSpecifierNonArray::F16Mat42 => "f16mat42".to_string(),
+        TypeSpecifierNonArray::F16Mat43 => "f16mat43".to_string(),
+        TypeSpecifierNonArray::F16Mat4 => "f16mat4".to_string(),
+        TypeSpecifierNonArray::Struct(s) => s.name.as_ref().map(|n| n.0.clone()).unwrap_or_else(|| "anonymous_struct".to_string()),
+        TypeSpecifierNonArray::TypeName(n) => n.0.clone(),
+        _ => panic!("array element type has no lexical constructor name (e.g. an opaque sampler type)"),
+    }
+}
 
 #[derive(Clone, PartialEq, Debug)]
 pub struct ConstArray {
@@ -880,6 +1109,56 @@ impl ConstArray {
             self.data.get(index)
         }
     }
+
+    /// The size of the outermost (slowest varying) array dimension, i.e. what GLSL's
+    /// `array.length()` returns for this array.
+    pub fn len(&self) -> u32 {
+        *self.dims.last().expect("array has at least one dimension")
+    }
+
+    /// Indexes into the outermost (slowest varying, last entry of `dims`) array dimension.
+    ///
+    /// Returns the contained [`ConstVal`] directly if this was the last remaining dimension, or a
+    /// smaller [`ConstArray`] with that dimension peeled off so chained `a[i][j]` indexing can
+    /// keep folding one dimension at a time.
+    pub fn index(&self, i: u32) -> Option<ConstVal> {
+        let outer_dim = *self.dims.last()?;
+        if i >= outer_dim {
+            return None;
+        }
+
+        if self.dims.len() == 1 {
+            return self.data.get(i as usize).cloned();
+        }
+
+        let inner_dims = &self.dims[..self.dims.len() - 1];
+        let inner_len: usize = inner_dims.iter().map(|&d| d as usize).product();
+        let start = inner_len * (i as usize);
+        let slice = self.data.get(start..start + inner_len)?;
+
+        Some(ConstVal::Array(ConstArray {
+            type_specifier: self.type_specifier.clone(),
+            dims: inner_dims.to_vec().into_boxed_slice(),
+            data: slice.to_vec().into_boxed_slice(),
+        }))
+    }
+
+    /// Emits a GLSL array-constructor expression `T[N](e0, e1, ...)`. Multi-dimensional arrays
+    /// nest one constructor per dimension, since [`Self::index`] peels dimensions off one at a
+    /// time and each element's own `as_expr` takes care of the next dimension down.
+    pub fn as_expr(&self) -> Expr {
+        let outer_dim = *self.dims.last().expect("array has at least one dimension");
+        let elements = (0..outer_dim)
+            .map(|i| self.index(i).expect("index within array bounds").as_expr())
+            .collect();
+
+        let callee = FunIdentifier::Expr(Box::new(Expr::Bracket(
+            Box::new(Expr::Variable(Identifier::from(type_specifier_non_array_name(&self.type_specifier)))),
+            Box::new(Expr::UIntConst(outer_dim)),
+        )));
+
+        Expr::FunCall(callee, elements)
+    }
 }
 
 #[derive(Clone, PartialEq, Debug)]
@@ -892,6 +1171,24 @@ impl ConstStruct {
     pub fn type_specifier(&self) -> TypeSpecifier {
         TypeSpecifier::new(TypeSpecifierNonArray::Struct(self.type_specifier.clone()))
     }
+
+    /// Emits the struct's constructor call `StructName(field0, field1, ...)`. Fields are read off
+    /// in `StructSpecifier` declaration order rather than `HashMap` iteration order, since the
+    /// latter is unspecified and would make the generated expression non-deterministic.
+    pub fn as_expr(&self) -> Expr {
+        let name = self.type_specifier.name.as_ref().expect("struct constructors require a named struct").0.clone();
+
+        let args = self.type_specifier.fields.iter()
+            .flat_map(|field| field.identifiers.iter())
+            .map(|arrayed_ident| {
+                self.entries.get(&arrayed_ident.ident.0)
+                    .expect("const struct value is missing a field declared in its StructSpecifier")
+                    .as_expr()
+            })
+            .collect();
+
+        Expr::FunCall(FunIdentifier::Identifier(Identifier::from(name)), args)
+    }
 }
 
 impl ConstLookup for ConstStruct {
@@ -924,7 +1221,11 @@ impl ConstVal {
     }
 
     pub fn as_expr(&self) -> Expr {
-        todo!()
+        match self {
+            ConstVal::Base(v) => v.as_expr(),
+            ConstVal::Array(v) => v.as_expr(),
+            ConstVal::Struct(v) => v.as_expr(),
+        }
     }
 }
 
@@ -960,6 +1261,93 @@ impl From<ConstValOrExpr> for Expr {
     }
 }
 
+/// Deduplicating arena for [`ConstBaseVal`]s.
+///
+/// Large const-folded shaders produce many structurally identical values (repeated `vec4(0.0)`
+/// splats, identity matrices, ...). Interning them here means two [`ConstId`]s for the same value
+/// are the same `Rc` allocation, so later passes can compare constants for equality with a pointer
+/// check instead of a full structural [`PartialEq`] walk.
+///
+/// This is infrastructure for that later pass: `const_propagate_expr` and the `OP_*`/`std_*`
+/// evaluators still pass `ConstBaseVal`s by value today, so interning doesn't happen automatically
+/// yet. Threading a `ConstantManager` through every evaluator call site (they're all
+/// `Fn(&[&ConstBaseVal]) -> ...` today) is a larger follow-up change of its own.
+pub struct ConstantManager {
+    interned: HashMap<(u8, Vec<u64>), Rc<ConstBaseVal>>,
+}
+
+impl ConstantManager {
+    pub fn new() -> Self {
+        Self {
+            interned: HashMap::new(),
+        }
+    }
+
+    /// Interns `val`, returning a handle shared with any previously interned value that is
+    /// structurally equal to it.
+    ///
+    /// Dedup is keyed on [`const_base_val_key`] rather than a derived `Hash` impl, since the
+    /// `f32`/`f64`/`f16` components backing `ConstBaseVal` don't implement `Eq`/`Hash`, and rather
+    /// than `val`'s `Debug` formatting, which diverges from `PartialEq` in both directions: it
+    /// prints `+0.0` and `-0.0` (which compare equal) differently, and collapses every NaN payload
+    /// (which never compare equal, even to themselves) to the same literal `"NaN"`.
+    pub fn intern(&mut self, val: ConstBaseVal) -> ConstId {
+        let key = const_base_val_key(&val);
+        if let Some(existing) = self.interned.get(&key) {
+            return ConstId(existing.clone());
+        }
+
+        let rc = Rc::new(val);
+        self.interned.insert(key, rc.clone());
+        ConstId(rc)
+    }
+}
+
+/// A canonical, bit-exact key for `val`'s components: a tag identifying `val`'s variant plus one
+/// `u64` per scalar component. Integers and bools round-trip bit-for-bit; float components have
+/// their sign bit cleared whenever the rest of the bits are already zero, so `+0.0` and `-0.0` key
+/// identically (as they compare under `PartialEq`) while distinct NaN bit patterns still key
+/// apart (as they never compare equal under `PartialEq`, even to themselves).
+fn const_base_val_key(val: &ConstBaseVal) -> (u8, Vec<u64>) {
+    fn canonical_float_bits(bits: u64, sign_and_magnitude_mask: u64) -> u64 {
+        if bits & sign_and_magnitude_mask == 0 { 0 } else { bits }
+    }
+
+    match val {
+        ConstBaseVal::Bool(v) => (0, v.column_iter().map(|b| *b as u64).collect()),
+        ConstBaseVal::Int(v) => (1, v.column_iter().map(|i| *i as u32 as u64).collect()),
+        ConstBaseVal::UInt(v) => (2, v.column_iter().map(|u| *u as u64).collect()),
+        ConstBaseVal::Float(v) => (3, v.column_iter().map(|f| canonical_float_bits(f.to_bits() as u64, 0x7FFF_FFFF)).collect()),
+        ConstBaseVal::Double(v) => (4, v.column_iter().map(|f| canonical_float_bits(f.to_bits(), 0x7FFF_FFFF_FFFF_FFFF)).collect()),
+        ConstBaseVal::Int8(v) => (5, v.column_iter().map(|i| *i as u8 as u64).collect()),
+        ConstBaseVal::Int16(v) => (6, v.column_iter().map(|i| *i as u16 as u64).collect()),
+        ConstBaseVal::Int64(v) => (7, v.column_iter().map(|i| *i as u64).collect()),
+        ConstBaseVal::UInt8(v) => (8, v.column_iter().map(|u| *u as u64).collect()),
+        ConstBaseVal::UInt16(v) => (9, v.column_iter().map(|u| *u as u64).collect()),
+        ConstBaseVal::UInt64(v) => (10, v.column_iter().map(|u| *u).collect()),
+        ConstBaseVal::Float16(v) => (11, v.column_iter().map(|f| canonical_float_bits(f.to_bits() as u64, 0x7FFF)).collect()),
+    }
+}
+
+/// A lightweight, cheaply cloneable handle to a [`ConstantManager`]-interned [`ConstBaseVal`].
+///
+/// Cloning a `ConstId` only bumps a reference count; two `ConstId`s produced by interning equal
+/// values are guaranteed to point at the same allocation, so [`ConstId::ptr_eq`] is a cheap
+/// substitute for comparing the underlying values structurally.
+#[derive(Clone)]
+pub struct ConstId(Rc<ConstBaseVal>);
+
+impl ConstId {
+    /// Borrows the concrete value behind this handle.
+    pub fn as_val(&self) -> &ConstBaseVal {
+        &self.0
+    }
+
+    pub fn ptr_eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.0, &other.0)
+    }
+}
+
 /// Propagates const values in a expression creating a either a new expression or a constant value.
 ///
 /// No other transformation besides constant evaluation will be applied.
@@ -984,10 +1372,10 @@ pub fn const_propagate_expr<CL: ConstLookup, FL: ConstEvalFunctionLookup>(expr:
             match op {
                 UnaryOp::Inc => Err(ConstEvalError::UnaryOpExpectedLValue(UnaryOp::Inc)),
                 UnaryOp::Dec => Err(ConstEvalError::UnaryOpExpectedLValue(UnaryOp::Dec)),
-                UnaryOp::Add => function::OP_UNARY_ADD.eval(&[a]).map(ConstValOrExpr::from).ok_or_else(err),
-                UnaryOp::Minus => function::OP_UNARY_MINUS.eval(&[a]).map(ConstValOrExpr::from).ok_or_else(err),
-                UnaryOp::Not => function::OP_UNARY_NOT.eval(&[a]).map(ConstValOrExpr::from).ok_or_else(err),
-                UnaryOp::Complement => function::OP_UNARY_COMPLEMENT.eval(&[a]).map(ConstValOrExpr::from).ok_or_else(err),
+                UnaryOp::Add => function::OP_UNARY_ADD.eval(&[a])?.map(ConstValOrExpr::from).ok_or_else(err),
+                UnaryOp::Minus => function::OP_UNARY_MINUS.eval(&[a])?.map(ConstValOrExpr::from).ok_or_else(err),
+                UnaryOp::Not => function::OP_UNARY_NOT.eval(&[a])?.map(ConstValOrExpr::from).ok_or_else(err),
+                UnaryOp::Complement => function::OP_UNARY_COMPLEMENT.eval(&[a])?.map(ConstValOrExpr::from).ok_or_else(err),
             }
         },
         Expr::Binary(op, a, b) => {
@@ -1003,30 +1391,30 @@ pub fn const_propagate_expr<CL: ConstLookup, FL: ConstEvalFunctionLookup>(expr:
             let err = || ConstEvalError::IllegalBinaryOperand(op.clone(), a_ty.clone(), b_ty.clone());
             let (a, b) = (a.try_into_base().ok_or_else(err)?, b.try_into_base().ok_or_else(err)?);
             match op {
-                BinaryOp::Or => function::OP_BINARY_OR.eval(&[a, b]).map(ConstValOrExpr::from).ok_or_else(err),
-                BinaryOp::Xor => function::OP_BINARY_XOR.eval(&[a, b]).map(ConstValOrExpr::from).ok_or_else(err),
-                BinaryOp::And => function::OP_BINARY_AND.eval(&[a, b]).map(ConstValOrExpr::from).ok_or_else(err),
-                BinaryOp::BitOr => function::OP_BINARY_BIT_OR.eval(&[a, b]).map(ConstValOrExpr::from).ok_or_else(err),
-                BinaryOp::BitXor => function::OP_BINARY_BIT_XOR.eval(&[a, b]).map(ConstValOrExpr::from).ok_or_else(err),
-                BinaryOp::BitAnd => function::OP_BINARY_BIT_AND.eval(&[a, b]).map(ConstValOrExpr::from).ok_or_else(err),
-                BinaryOp::Equal => function::OP_BINARY_EQUAL.eval(&[a, b]).map(ConstValOrExpr::from).ok_or_else(err),
+                BinaryOp::Or => function::OP_BINARY_OR.eval(&[a, b])?.map(ConstValOrExpr::from).ok_or_else(err),
+                BinaryOp::Xor => function::OP_BINARY_XOR.eval(&[a, b])?.map(ConstValOrExpr::from).ok_or_else(err),
+                BinaryOp::And => function::OP_BINARY_AND.eval(&[a, b])?.map(ConstValOrExpr::from).ok_or_else(err),
+                BinaryOp::BitOr => function::OP_BINARY_BIT_OR.eval(&[a, b])?.map(ConstValOrExpr::from).ok_or_else(err),
+                BinaryOp::BitXor => function::OP_BINARY_BIT_XOR.eval(&[a, b])?.map(ConstValOrExpr::from).ok_or_else(err),
+                BinaryOp::BitAnd => function::OP_BINARY_BIT_AND.eval(&[a, b])?.map(ConstValOrExpr::from).ok_or_else(err),
+                BinaryOp::Equal => function::OP_BINARY_EQUAL.eval(&[a, b])?.map(ConstValOrExpr::from).ok_or_else(err),
                 BinaryOp::NonEqual => {
-                    function::OP_BINARY_EQUAL.eval(&[a, b]).map(|v| {
+                    function::OP_BINARY_EQUAL.eval(&[a, b])?.map(|v| {
                         let val: bool = v.try_into().expect("OP_BINARY_EQUAL did not return bool scalar");
                         Some(ConstBaseVal::new_bool(!val))
                     }).flatten().map(ConstValOrExpr::from).ok_or_else(err)
                 },
-                BinaryOp::LT => function::OP_BINARY_LT.eval(&[a, b]).map(ConstValOrExpr::from).ok_or_else(err),
-                BinaryOp::GT => function::OP_BINARY_GT.eval(&[a, b]).map(ConstValOrExpr::from).ok_or_else(err),
-                BinaryOp::LTE => function::OP_BINARY_LTE.eval(&[a, b]).map(ConstValOrExpr::from).ok_or_else(err),
-                BinaryOp::GTE => function::OP_BINARY_GTE.eval(&[a, b]).map(ConstValOrExpr::from).ok_or_else(err),
-                BinaryOp::LShift => function::OP_BINARY_LSHIFT.eval(&[a, b]).map(ConstValOrExpr::from).ok_or_else(err),
-                BinaryOp::RShift => function::OP_BINARY_RSHIFT.eval(&[a, b]).map(ConstValOrExpr::from).ok_or_else(err),
-                BinaryOp::Add => function::OP_BINARY_ADD.eval(&[a, b]).map(ConstValOrExpr::from).ok_or_else(err),
-                BinaryOp::Sub => function::OP_BINARY_SUB.eval(&[a, b]).map(ConstValOrExpr::from).ok_or_else(err),
-                BinaryOp::Mult => function::OP_BINARY_MULT.eval(&[a, b]).map(ConstValOrExpr::from).ok_or_else(err),
-                BinaryOp::Div => function::OP_BINARY_DIV.eval(&[a, b]).map(ConstValOrExpr::from).ok_or_else(err),
-                BinaryOp::Mod => function::OP_BINARY_MOD.eval(&[a, b]).map(ConstValOrExpr::from).ok_or_else(err),
+                BinaryOp::LT => function::OP_BINARY_LT.eval(&[a, b])?.map(ConstValOrExpr::from).ok_or_else(err),
+                BinaryOp::GT => function::OP_BINARY_GT.eval(&[a, b])?.map(ConstValOrExpr::from).ok_or_else(err),
+                BinaryOp::LTE => function::OP_BINARY_LTE.eval(&[a, b])?.map(ConstValOrExpr::from).ok_or_else(err),
+                BinaryOp::GTE => function::OP_BINARY_GTE.eval(&[a, b])?.map(ConstValOrExpr::from).ok_or_else(err),
+                BinaryOp::LShift => function::OP_BINARY_LSHIFT.eval(&[a, b])?.map(ConstValOrExpr::from).ok_or_else(err),
+                BinaryOp::RShift => function::OP_BINARY_RSHIFT.eval(&[a, b])?.map(ConstValOrExpr::from).ok_or_else(err),
+                BinaryOp::Add => function::OP_BINARY_ADD.eval(&[a, b])?.map(ConstValOrExpr::from).ok_or_else(err),
+                BinaryOp::Sub => function::OP_BINARY_SUB.eval(&[a, b])?.map(ConstValOrExpr::from).ok_or_else(err),
+                BinaryOp::Mult => function::OP_BINARY_MULT.eval(&[a, b])?.map(ConstValOrExpr::from).ok_or_else(err),
+                BinaryOp::Div => function::OP_BINARY_DIV.eval(&[a, b])?.map(ConstValOrExpr::from).ok_or_else(err),
+                BinaryOp::Mod => function::OP_BINARY_MOD.eval(&[a, b])?.map(ConstValOrExpr::from).ok_or_else(err),
             }
         },
         Expr::Ternary(a, b, c) => {
@@ -1051,11 +1439,53 @@ pub fn const_propagate_expr<CL: ConstLookup, FL: ConstEvalFunctionLookup>(expr:
             let b = const_propagate_expr(b, cl, fl)?;
             Ok(Expr::Assignment(Box::new(a), op.clone(), Box::new(b.into())).into())
         },
-        Expr::Bracket(_, _) => todo!(),
+        Expr::Bracket(a, b) => {
+            let a = const_propagate_expr(a, cl, fl)?;
+            let b = const_propagate_expr(b, cl, fl)?;
+
+            let (a, b) = match (a, b) {
+                (ConstValOrExpr::Const(a), ConstValOrExpr::Const(b)) => (a, b),
+                (a, b) => return Ok(Expr::Bracket(Box::new(a.into()), Box::new(b.into())).into()),
+            };
+
+            let index = match &b {
+                ConstVal::Base(ConstBaseVal::Int(ConstSVVal::Scalar(v))) if *v >= 0 => *v as u32,
+                ConstVal::Base(ConstBaseVal::UInt(ConstSVVal::Scalar(v))) => *v,
+                _ => return Err(ConstEvalError::IllegalArrayIndex),
+            };
+
+            match a {
+                ConstVal::Array(arr) => arr.index(index).map(ConstValOrExpr::from).ok_or(ConstEvalError::ArrayIndexOutOfRange),
+                ConstVal::Base(base) => const_propagate_index(&base, index).map(ConstValOrExpr::from),
+                ConstVal::Struct(_) => Err(ConstEvalError::ArrayIndexScalar),
+            }
+        },
         Expr::FunCall(ident, params) => {
             // Generates the propagated expression when called
             let ret = |p: Vec<ConstValOrExpr>| Ok(Expr::FunCall(ident.clone(), p.into_iter().map(Expr::from).collect()).into());
 
+            // `arr.length()` parses as a call whose callee is `Dot(arr, "length")` rather than a
+            // plain identifier, so it has to be special-cased ahead of the normal overload lookup
+            // below, which only ever looks at `FunIdentifier::Identifier`.
+            if let FunIdentifier::Expr(callee) = ident {
+                if let Expr::Dot(base, member) = callee.as_ref() {
+                    if member.0 == "length" && params.is_empty() {
+                        return match const_propagate_expr(base, cl, fl)? {
+                            ConstValOrExpr::Const(ConstVal::Array(arr)) => Ok(ConstBaseVal::new_int(arr.len() as i32).into()),
+                            ConstValOrExpr::Const(ConstVal::Base(base)) => match const_length(&base) {
+                                Some(len) => Ok(ConstBaseVal::new_int(len as i32).into()),
+                                None => Err(ConstEvalError::ArrayLengthRequiresArray),
+                            },
+                            ConstValOrExpr::Const(ConstVal::Struct(_)) => Err(ConstEvalError::ArrayLengthRequiresArray),
+                            ConstValOrExpr::Expr(expr) => {
+                                let callee = FunIdentifier::Expr(Box::new(Expr::Dot(Box::new(expr), member.clone())));
+                                Ok(Expr::FunCall(callee, Vec::new()).into())
+                            },
+                        };
+                    }
+                }
+            }
+
             let params = params.iter().map(|e| const_propagate_expr(e, cl, fl)).collect::<Result<Vec<_>, ConstEvalError>>()?;
             let param_ref = params.iter().map(|v| match v {
                 ConstValOrExpr::Const(ConstVal::Base(b)) => Some(b),
@@ -1066,14 +1496,20 @@ pub fn const_propagate_expr<CL: ConstLookup, FL: ConstEvalFunctionLookup>(expr:
                 None => return ret(params),
             };
 
-            let func = match ident {
+            let (func, is_constructor) = match ident {
                 FunIdentifier::Identifier(ident) => match fl.lookup(ident) {
-                    Some(func) => func,
+                    Some(func) => (func, function::is_constructor_name(&ident.0)),
                     None => return ret(params),
                 },
                 FunIdentifier::Expr(_) => return ret(params),
             };
-            func.eval(&param_ref).map(ConstVal::Base).map(ConstValOrExpr::from).ok_or(ConstEvalError::NoMatchingFunctionOverload)
+            func.eval(&param_ref)?.map(ConstVal::Base).map(ConstValOrExpr::from).ok_or_else(|| {
+                if is_constructor {
+                    ConstEvalError::IllegalConstructor
+                } else {
+                    ConstEvalError::NoMatchingFunctionOverload
+                }
+            })
         },
         Expr::Dot(a, ident) => {
             let a = const_propagate_expr(a, cl, fl)?;
@@ -1084,7 +1520,9 @@ pub fn const_propagate_expr<CL: ConstLookup, FL: ConstEvalFunctionLookup>(expr:
 
             match a {
                 ConstVal::Struct(str) => str.lookup_const(ident).cloned().map(ConstValOrExpr::from).ok_or_else(|| ConstEvalError::UnknownStructureMember(ident.0.clone())),
-                ConstVal::Array(a) => todo!(),
+                ConstVal::Base(base) if base.get_shape().is_vector() => const_propagate_swizzle(&base, &ident.0).map(ConstValOrExpr::from),
+                // Arrays have no member besides `.length()`, which is handled in the `FunCall`
+                // arm above (it always appears as a method call, never a bare `Dot`).
                 _ => Err(ConstEvalError::DotStructureRequired),
             }
         },
@@ -1108,6 +1546,138 @@ pub fn const_propagate_expr<CL: ConstLookup, FL: ConstEvalFunctionLookup>(expr:
     }
 }
 
+/// The value `.length()` folds to for a constant vector or matrix: its component count, or its
+/// column count (the first number in GLSL's `matCxR` naming) for a matrix. [`None`] for a scalar,
+/// which `.length()` is not defined on.
+fn const_length(base: &ConstBaseVal) -> Option<u32> {
+    match base.get_shape() {
+        BaseTypeShape::Scalar => None,
+        BaseTypeShape::Vec2 => Some(2),
+        BaseTypeShape::Vec3 => Some(3),
+        BaseTypeShape::Vec4 => Some(4),
+        BaseTypeShape::Mat2 | BaseTypeShape::Mat23 | BaseTypeShape::Mat24 => Some(2),
+        BaseTypeShape::Mat32 | BaseTypeShape::Mat3 | BaseTypeShape::Mat34 => Some(3),
+        BaseTypeShape::Mat42 | BaseTypeShape::Mat43 | BaseTypeShape::Mat4 => Some(4),
+    }
+}
+
+/// Folds a GLSL vector swizzle (`.xyz`, `.rgba`, `.stpq`, …) on a constant vector value.
+///
+/// Swizzle masks may mix repeated components (`.xxyy`) but must draw every character from the
+/// same naming set (`xyzw`/`rgba`/`stpq`) and must not index past the source vector's component
+/// count.
+fn const_propagate_swizzle(base: &ConstBaseVal, mask: &str) -> Result<ConstVal, ConstEvalError> {
+    if mask.is_empty() || mask.len() > 4 {
+        return Err(ConstEvalError::IllegalSwizzle(mask.to_string()));
+    }
+
+    let component_count = match base.get_shape() {
+        BaseTypeShape::Vec2 => 2,
+        BaseTypeShape::Vec3 => 3,
+        BaseTypeShape::Vec4 => 4,
+        _ => return Err(ConstEvalError::DotStructureRequired),
+    };
+
+    let mut set = None;
+    let mut indices = Vec::with_capacity(mask.len());
+    for c in mask.chars() {
+        let (this_set, index) = match c {
+            'x' => (0, 0), 'y' => (0, 1), 'z' => (0, 2), 'w' => (0, 3),
+            'r' => (1, 0), 'g' => (1, 1), 'b' => (1, 2), 'a' => (1, 3),
+            's' => (2, 0), 't' => (2, 1), 'p' => (2, 2), 'q' => (2, 3),
+            _ => return Err(ConstEvalError::IllegalSwizzle(mask.to_string())),
+        };
+        match set {
+            None => set = Some(this_set),
+            Some(set) if set != this_set => return Err(ConstEvalError::IllegalSwizzle(mask.to_string())),
+            _ => {}
+        }
+        if index >= component_count {
+            return Err(ConstEvalError::SwizzleComponentOutOfRange(mask.to_string()));
+        }
+        indices.push(index);
+    }
+
+    macro_rules! swizzle_build {
+        ($wrapper:ident, $variant:ident, $v:expr) => {{
+            let data: Vec<_> = $v.column_iter().cloned().collect();
+            match indices.len() {
+                1 => ConstBaseVal::$variant($wrapper::Scalar(data[indices[0]].clone())),
+                2 => ConstBaseVal::$variant($wrapper::new_vec2(Vector2::new(data[indices[0]].clone(), data[indices[1]].clone()))),
+                3 => ConstBaseVal::$variant($wrapper::new_vec3(Vector3::new(data[indices[0]].clone(), data[indices[1]].clone(), data[indices[2]].clone()))),
+                4 => ConstBaseVal::$variant($wrapper::new_vec4(Vector4::new(data[indices[0]].clone(), data[indices[1]].clone(), data[indices[2]].clone(), data[indices[3]].clone()))),
+                _ => unreachable!(),
+            }
+        }};
+    }
+
+    let result = match base {
+        ConstBaseVal::Bool(ConstSVVal::Vector(v)) => swizzle_build!(ConstSVVal, Bool, v),
+        ConstBaseVal::Int(ConstSVVal::Vector(v)) => swizzle_build!(ConstSVVal, Int, v),
+        ConstBaseVal::UInt(ConstSVVal::Vector(v)) => swizzle_build!(ConstSVVal, UInt, v),
+        ConstBaseVal::Float(ConstSVMVal::Vector(v)) => swizzle_build!(ConstSVMVal, Float, v),
+        ConstBaseVal::Double(ConstSVMVal::Vector(v)) => swizzle_build!(ConstSVMVal, Double, v),
+        _ => return Err(ConstEvalError::DotStructureRequired),
+    };
+
+    Ok(ConstVal::Base(result))
+}
+
+/// Folds a constant subscript (`v[i]`) into a scalar component of a vector or a column vector of
+/// a matrix, matching GLSL's column-major `m[i]` semantics.
+fn const_propagate_index(base: &ConstBaseVal, index: u32) -> Result<ConstVal, ConstEvalError> {
+    macro_rules! index_sv {
+        ($variant:ident, $v:expr) => {{
+            let data: Vec<_> = $v.column_iter().cloned().collect();
+            let c = data.get(index as usize).cloned().ok_or(ConstEvalError::ArrayIndexOutOfRange)?;
+            Ok(ConstVal::Base(ConstBaseVal::$variant(ConstSVVal::Scalar(c))))
+        }};
+    }
+    macro_rules! index_svm_vec {
+        ($variant:ident, $v:expr) => {{
+            let data: Vec<_> = $v.column_iter().cloned().collect();
+            let c = data.get(index as usize).cloned().ok_or(ConstEvalError::ArrayIndexOutOfRange)?;
+            Ok(ConstVal::Base(ConstBaseVal::$variant(ConstSVMVal::Scalar(c))))
+        }};
+    }
+    macro_rules! index_svm_mat {
+        ($variant:ident, $m:expr) => {{
+            if (index as usize) >= $m.ncols() {
+                return Err(ConstEvalError::ArrayIndexOutOfRange);
+            }
+            let col = $m.column(index as usize).clone_owned();
+            Ok(ConstVal::Base(ConstBaseVal::$variant(ConstSVMVal::Vector(col.into()))))
+        }};
+    }
+
+    match base {
+        ConstBaseVal::Bool(ConstSVVal::Vector(v)) => index_sv!(Bool, v),
+        ConstBaseVal::Int(ConstSVVal::Vector(v)) => index_sv!(Int, v),
+        ConstBaseVal::UInt(ConstSVVal::Vector(v)) => index_sv!(UInt, v),
+        ConstBaseVal::Float(ConstSVMVal::Vector(v)) => index_svm_vec!(Float, v),
+        ConstBaseVal::Double(ConstSVMVal::Vector(v)) => index_svm_vec!(Double, v),
+        ConstBaseVal::Float(ConstSVMVal::Matrix(ConstMVal::Mat2(m))) => index_svm_mat!(Float, m),
+        ConstBaseVal::Float(ConstSVMVal::Matrix(ConstMVal::Mat23(m))) => index_svm_mat!(Float, m),
+        ConstBaseVal::Float(ConstSVMVal::Matrix(ConstMVal::Mat24(m))) => index_svm_mat!(Float, m),
+        ConstBaseVal::Float(ConstSVMVal::Matrix(ConstMVal::Mat32(m))) => index_svm_mat!(Float, m),
+        ConstBaseVal::Float(ConstSVMVal::Matrix(ConstMVal::Mat3(m))) => index_svm_mat!(Float, m),
+        ConstBaseVal::Float(ConstSVMVal::Matrix(ConstMVal::Mat34(m))) => index_svm_mat!(Float, m),
+        ConstBaseVal::Float(ConstSVMVal::Matrix(ConstMVal::Mat42(m))) => index_svm_mat!(Float, m),
+        ConstBaseVal::Float(ConstSVMVal::Matrix(ConstMVal::Mat43(m))) => index_svm_mat!(Float, m),
+        ConstBaseVal::Float(ConstSVMVal::Matrix(ConstMVal::Mat4(m))) => index_svm_mat!(Float, m),
+        ConstBaseVal::Double(ConstSVMVal::Matrix(ConstMVal::Mat2(m))) => index_svm_mat!(Double, m),
+        ConstBaseVal::Double(ConstSVMVal::Matrix(ConstMVal::Mat23(m))) => index_svm_mat!(Double, m),
+        ConstBaseVal::Double(ConstSVMVal::Matrix(ConstMVal::Mat24(m))) => index_svm_mat!(Double, m),
+        ConstBaseVal::Double(ConstSVMVal::Matrix(ConstMVal::Mat32(m))) => index_svm_mat!(Double, m),
+        ConstBaseVal::Double(ConstSVMVal::Matrix(ConstMVal::Mat3(m))) => index_svm_mat!(Double, m),
+        ConstBaseVal::Double(ConstSVMVal::Matrix(ConstMVal::Mat34(m))) => index_svm_mat!(Double, m),
+        ConstBaseVal::Double(ConstSVMVal::Matrix(ConstMVal::Mat42(m))) => index_svm_mat!(Double, m),
+        ConstBaseVal::Double(ConstSVMVal::Matrix(ConstMVal::Mat43(m))) => index_svm_mat!(Double, m),
+        ConstBaseVal::Double(ConstSVMVal::Matrix(ConstMVal::Mat4(m))) => index_svm_mat!(Double, m),
+        _ => Err(ConstEvalError::ArrayIndexScalar),
+    }
+}
+
 #[derive(Clone, PartialEq, Debug)]
 #[non_exhaustive]
 pub enum ConstEvalError {
@@ -1124,6 +1694,16 @@ pub enum ConstEvalError {
     IllegalBinaryOp(BinaryOp),
     IllegalBinaryOperand(BinaryOp, TypeSpecifier, TypeSpecifier),
     NoMatchingFunctionOverload,
+    AmbiguousFunctionOverload,
+    ParameterCountMismatch { expected: usize, got: usize },
+    ImplicitCastFailed { from: function::ParameterType, to: function::ParameterType },
+    IllegalConstructor,
+    IllegalSwizzle(String),
+    SwizzleComponentOutOfRange(String),
+    IllegalArrayIndex,
+    ArrayIndexOutOfRange,
+    ArrayIndexScalar,
+    ArrayLengthRequiresArray,
 }
 
 struct ScopedConstLookup {
@@ -1165,13 +1745,55 @@ struct ConstPropagateVisitor {
     value_lookup: ScopedConstLookup,
 }
 
+impl ConstPropagateVisitor {
+    fn new() -> Self {
+        Self {
+            value_lookup: ScopedConstLookup::new(),
+        }
+    }
+
+    /// Folds the initializer(s) of a `const`-qualified [`InitDeclaratorList`], recording any
+    /// initializer that reduces to a literal so later `Expr::Variable` references resolve through
+    /// `lookup_const`. Non-`const` declarations are left untouched; their initializers are still
+    /// reached (and folded) by the default expression traversal.
+    fn fold_init_declarator_list(&mut self, init: &mut InitDeclaratorList) {
+        let is_const = init.head.ty.qualifier.as_ref().map_or(false, |qualifier| {
+            qualifier.qualifiers.0.iter().any(|spec| matches!(spec, TypeQualifierSpec::Storage(StorageQualifier::Const)))
+        });
+        if !is_const {
+            return;
+        }
+
+        if let Some(name) = init.head.name.clone() {
+            self.fold_initializer(&name, &mut init.head.initializer);
+        }
+        for tail in &mut init.tail {
+            let name = tail.ident.ident.clone();
+            self.fold_initializer(&name, &mut tail.initializer);
+        }
+    }
+
+    /// Const-propagates a single declarator's initializer expression, recording the result as the
+    /// bound value of `name` if it fully reduces to a [`ConstVal`], and replacing the initializer
+    /// expression in place with whatever it reduced to.
+    fn fold_initializer(&mut self, name: &Identifier, initializer: &mut Option<Initializer>) {
+        let Some(Initializer::Simple(expr)) = initializer else { return };
+        let Ok(folded) = const_propagate_expr(expr, &self.value_lookup, &function::BUILTIN_CONST_FUNCTIONS) else { return };
+
+        if let ConstValOrExpr::Const(val) = &folded {
+            self.value_lookup.set_value(name, val.clone());
+        }
+        **expr = folded.into();
+    }
+}
+
 impl VisitorMut for ConstPropagateVisitor {
     fn visit_external_declaration(&mut self, decl: &mut ExternalDeclaration) -> Visit {
         if let ExternalDeclaration::Declaration(decl) = decl {
             match decl {
                 Declaration::FunctionPrototype(_) => {}
                 Declaration::InitDeclaratorList(init) => {
-                    todo!()
+                    self.fold_init_declarator_list(init);
                 }
                 Declaration::Block(_) => {}
                 Declaration::Global(_, _) => {}
@@ -1179,16 +1801,46 @@ impl VisitorMut for ConstPropagateVisitor {
             }
         }
 
-        todo!()
+        Visit::Children
+    }
+
+    /// Pushes a fresh scope before visiting a block's statements and pops it afterwards, so
+    /// `const` bindings declared inside the block shadow outer ones and don't leak back out.
+    fn visit_compound_statement(&mut self, compound: &mut CompoundStatement) -> Visit {
+        self.value_lookup.push_scope();
+        for statement in &mut compound.statement_list {
+            statement.visit_mut(self);
+        }
+        self.value_lookup.pop_scope();
+        Visit::Parent
+    }
+
+    fn visit_simple_statement(&mut self, statement: &mut SimpleStatement) -> Visit {
+        if let SimpleStatement::Declaration(Declaration::InitDeclaratorList(init)) = statement {
+            self.fold_init_declarator_list(init);
+        }
+
+        Visit::Children
+    }
+
+    fn visit_expr(&mut self, expr: &mut Expr) -> Visit {
+        if let Ok(folded) = const_propagate_expr(expr, &self.value_lookup, &function::BUILTIN_CONST_FUNCTIONS) {
+            *expr = folded.into();
+        }
+
+        Visit::Parent
     }
 }
 
+/// Walks `shader`, folding every statically-known `const` expression to its literal form. The
+/// resulting tree has all compile-time-constant values collapsed to literals, which later passes
+/// (e.g. dead-branch elimination) can rely on without re-deriving them.
 pub fn const_propagate_unit(shader: &mut TranslationUnit) {
-
+    let mut visitor = ConstPropagateVisitor::new();
+    shader.visit_mut(&mut visitor);
 }
 
 mod function {
-    use std::any::TypeId;
     use std::cmp::Ordering;
     use std::collections::HashMap;
     use std::marker::PhantomData;
@@ -1202,13 +1854,14 @@ mod function {
     use num_traits::{One, Zero};
 
     use super::{ConstEvalFunctionLookup, ConstGenericValue, ConstGenericMappable, ConstGenericZipMappable};
-    use super::{BaseTypeShape, ConstBaseVal, ConstMVal, ConstSVMVal, ConstSVVal};
+    use super::{BaseTypeShape, ConstBaseVal, ConstEvalError, ConstMVal, ConstSVMVal, ConstSVVal};
 
     #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Debug)]
     pub enum ParameterBaseType {
         Bool,
         Int,
         UInt,
+        Half,
         Float,
         Double,
     }
@@ -1221,26 +1874,39 @@ mod function {
                 ConstBaseVal::UInt(_) => Self::UInt,
                 ConstBaseVal::Float(_) => Self::Float,
                 ConstBaseVal::Double(_) => Self::Double,
+                ConstBaseVal::Float16(_) => Self::Half,
             }
         }
 
         /// Ordered by glsl implicit casting rules. If a < b then a can be implicitly cast to b.
+        ///
+        /// `Half` sits between `Int`/`UInt` and `Float` in the lattice: `int`/`uint` widen to
+        /// `float16_t`, and `float16_t` widens further to `float`/`double`, but neither direction
+        /// reverses (a `float` or `double` constant never implicitly narrows down to `Half`).
         pub fn cast_cmp(&self, other: &Self) -> Option<Ordering> {
             if self == other {
                 Some(Ordering::Equal)
             } else {
                 match (self, other) {
                     (Self::Int, Self::UInt) |
+                    (Self::Int, Self::Half) |
                     (Self::Int, Self::Float) |
                     (Self::Int, Self::Double) |
+                    (Self::UInt, Self::Half) |
                     (Self::UInt, Self::Float) |
                     (Self::UInt, Self::Double) |
+                    (Self::Half, Self::Float) |
+                    (Self::Half, Self::Double) |
                     (Self::Float, Self::Double) => Some(Ordering::Less),
                     (Self::UInt, Self::Int) |
+                    (Self::Half, Self::Int) |
                     (Self::Float, Self::Int) |
                     (Self::Double, Self::Int) |
+                    (Self::Half, Self::UInt) |
                     (Self::Float, Self::UInt) |
                     (Self::Double, Self::UInt) |
+                    (Self::Float, Self::Half) |
+                    (Self::Double, Self::Half) |
                     (Self::Double, Self::Float) => Some(Ordering::Greater),
                     _ => None,
                 }
@@ -1254,6 +1920,31 @@ mod function {
                 _ => false
             }
         }
+
+        /// The number of implicit-promotion steps needed to cast `self` into `other`, used as the
+        /// per-argument conversion cost during overload resolution. `Some(0)` for an exact match,
+        /// increasing for each promotion step along the `Int -> UInt -> Half -> Float -> Double` chain
+        /// (so `int -> uint` costs less than `int -> float`, which costs less than `int ->
+        /// double`). `None` if `self` cannot be implicitly cast into `other` at all.
+        pub fn conversion_cost(&self, other: &Self) -> Option<u32> {
+            fn rank(ty: ParameterBaseType) -> u32 {
+                match ty {
+                    ParameterBaseType::Bool | ParameterBaseType::Int => 0,
+                    ParameterBaseType::UInt => 1,
+                    ParameterBaseType::Half => 2,
+                    ParameterBaseType::Float => 3,
+                    ParameterBaseType::Double => 4,
+                }
+            }
+
+            if self == other {
+                Some(0)
+            } else if self.can_cast_into(other) {
+                Some(rank(*other) - rank(*self))
+            } else {
+                None
+            }
+        }
     }
 
     #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Debug)]
@@ -1297,6 +1988,42 @@ mod function {
                 ParameterShape::GenericSVM => true,
             }
         }
+
+        /// The extra conversion cost of binding through this parameter shape, used alongside
+        /// [`ParameterBaseType::conversion_cost`] during overload resolution. An exact shape
+        /// (`Scalar`, `Vec2`, `Mat3`, ...) costs nothing; a shape-generic parameter
+        /// (`GenericSV`/`GenericM`/`GenericSVM`) costs more so that an overload with the exact
+        /// argument shape is always preferred over a generic one that merely accepts it.
+        pub fn conversion_cost(&self) -> u32 {
+            match self {
+                ParameterShape::GenericSV | ParameterShape::GenericM => 1,
+                ParameterShape::GenericSVM => 2,
+                _ => 0,
+            }
+        }
+    }
+
+    impl From<BaseTypeShape> for ParameterShape {
+        /// Converts a value's concrete shape into the matching exact `ParameterShape`. Used to
+        /// describe an actual argument's shape in error reporting; never produces a generic
+        /// variant since values never have a generic shape, only parameter prototypes do.
+        fn from(shape: BaseTypeShape) -> Self {
+            match shape {
+                BaseTypeShape::Scalar => ParameterShape::Scalar,
+                BaseTypeShape::Vec2 => ParameterShape::Vec2,
+                BaseTypeShape::Vec3 => ParameterShape::Vec3,
+                BaseTypeShape::Vec4 => ParameterShape::Vec4,
+                BaseTypeShape::Mat2 => ParameterShape::Mat2,
+                BaseTypeShape::Mat23 => ParameterShape::Mat23,
+                BaseTypeShape::Mat24 => ParameterShape::Mat24,
+                BaseTypeShape::Mat32 => ParameterShape::Mat32,
+                BaseTypeShape::Mat3 => ParameterShape::Mat3,
+                BaseTypeShape::Mat34 => ParameterShape::Mat34,
+                BaseTypeShape::Mat42 => ParameterShape::Mat42,
+                BaseTypeShape::Mat43 => ParameterShape::Mat43,
+                BaseTypeShape::Mat4 => ParameterShape::Mat4,
+            }
+        }
     }
 
     #[derive(Copy, Clone, Eq, PartialEq, Debug)]
@@ -1332,16 +2059,22 @@ mod function {
         }
     }
 
+    /// Builds the [`ConstEvalError::ImplicitCastFailed`] `from` field describing `val`'s actual
+    /// type, to be paired with the `to` prototype type the cast was attempted against.
+    fn exact_parameter_type(val: &ConstBaseVal) -> ParameterType {
+        ParameterType::new(ParameterBaseType::from_const_val(val), val.get_shape().into())
+    }
+
     /// A instance of a const evaluable function. It has a fixed prototype and can be called to
     /// evaluate some parameters matching the prototype.
     pub struct ConstEvalFunctionInstance {
         prototype: Option<Box<[ParameterType]>>,
-        function: Box<dyn Fn(&[&ConstBaseVal]) -> Option<ConstBaseVal> + Send + Sync>,
+        function: Box<dyn Fn(&[&ConstBaseVal]) -> Result<Option<ConstBaseVal>, ConstEvalError> + Send + Sync>,
     }
 
     impl ConstEvalFunctionInstance {
         pub fn from_generic<F>(f: F) -> Self where F: Fn(&[&ConstBaseVal]) -> Option<ConstBaseVal> + Send + Sync + 'static {
-            let function = Box::new(f);
+            let function = Box::new(move |params: &[&ConstBaseVal]| Ok(f(params)));
 
             Self {
                 prototype: None,
@@ -1353,9 +2086,9 @@ mod function {
             let prototype = Some(Box::new([]) as Box<[ParameterType]>);
             let function = Box::new(move |params: &[&ConstBaseVal]| {
                 if params.len() != 0 {
-                    panic!("Parameter list length mismatch. Expected 0 but got {:?}", params.len());
+                    Err(ConstEvalError::ParameterCountMismatch { expected: 0, got: params.len() })
                 } else {
-                    Some(f().into_const_base_val())
+                    Ok(Some(f().into_const_base_val()))
                 }
             });
 
@@ -1369,11 +2102,10 @@ mod function {
             let prototype = Some(Box::new([T0::get_type()]) as Box<[ParameterType]>);
             let function = Box::new(move |params: &[&ConstBaseVal]| {
                 if params.len() != 1 {
-                    panic!("Parameter list length mismatch. Expected 1 but got {:?}", params.len());
-                } else {
-                    let t0 = T0::try_cast_from(params[0]).unwrap_or_else(|| panic!("Implicit cast failed: {:?} to {:?}", params[0].type_specifier(), TypeId::of::<T0>()));
-                    f(t0).map(R::into_const_base_val)
+                    return Err(ConstEvalError::ParameterCountMismatch { expected: 1, got: params.len() });
                 }
+                let t0 = T0::try_cast_from(params[0]).ok_or_else(|| ConstEvalError::ImplicitCastFailed { from: exact_parameter_type(params[0]), to: T0::get_type() })?;
+                Ok(f(t0).map(R::into_const_base_val))
             });
 
             Self {
@@ -1386,12 +2118,29 @@ mod function {
             let prototype = Some(Box::new([T0::get_type(), T1::get_type()]) as Box<[ParameterType]>);
             let function = Box::new(move |params: &[&ConstBaseVal]| {
                 if params.len() != 2 {
-                    panic!("Parameter list length mismatch. Expected 2 but got {:?}", params.len());
-                } else {
-                    let t0 = T0::try_cast_from(params[0]).unwrap_or_else(|| panic!("Implicit cast failed: {:?} to {:?}", params[0].type_specifier(), TypeId::of::<T0>()));
-                    let t1 = T1::try_cast_from(params[1]).unwrap_or_else(|| panic!("Implicit cast failed: {:?} to {:?}", params[1].type_specifier(), TypeId::of::<T0>()));
-                    f(t0, t1).map(R::into_const_base_val)
+                    return Err(ConstEvalError::ParameterCountMismatch { expected: 2, got: params.len() });
+                }
+                let t0 = T0::try_cast_from(params[0]).ok_or_else(|| ConstEvalError::ImplicitCastFailed { from: exact_parameter_type(params[0]), to: T0::get_type() })?;
+                let t1 = T1::try_cast_from(params[1]).ok_or_else(|| ConstEvalError::ImplicitCastFailed { from: exact_parameter_type(params[1]), to: T1::get_type() })?;
+                Ok(f(t0, t1).map(R::into_const_base_val))
+            });
+
+            Self {
+                prototype,
+                function
+            }
+        }
+
+        pub fn from_fn_3<R, T0, T1, T2, F>(f: F) -> Self where R: ConstParameter, T0: ConstParameter + 'static, T1: ConstParameter + 'static, T2: ConstParameter + 'static, F: Fn(T0, T1, T2) -> Option<R> + Send + Sync + 'static {
+            let prototype = Some(Box::new([T0::get_type(), T1::get_type(), T2::get_type()]) as Box<[ParameterType]>);
+            let function = Box::new(move |params: &[&ConstBaseVal]| {
+                if params.len() != 3 {
+                    return Err(ConstEvalError::ParameterCountMismatch { expected: 3, got: params.len() });
                 }
+                let t0 = T0::try_cast_from(params[0]).ok_or_else(|| ConstEvalError::ImplicitCastFailed { from: exact_parameter_type(params[0]), to: T0::get_type() })?;
+                let t1 = T1::try_cast_from(params[1]).ok_or_else(|| ConstEvalError::ImplicitCastFailed { from: exact_parameter_type(params[1]), to: T1::get_type() })?;
+                let t2 = T2::try_cast_from(params[2]).ok_or_else(|| ConstEvalError::ImplicitCastFailed { from: exact_parameter_type(params[2]), to: T2::get_type() })?;
+                Ok(f(t0, t1, t2).map(R::into_const_base_val))
             });
 
             Self {
@@ -1400,6 +2149,31 @@ mod function {
             }
         }
 
+        /// Computes the total conversion cost of calling this overload with `params`, or
+        /// [`None`] if `params` is not compatible with this prototype (wrong argument count,
+        /// a shape that cannot bind, or a base type with no implicit cast to the required one).
+        ///
+        /// The cost is the sum of each argument's [`ParameterBaseType::conversion_cost`] plus
+        /// its [`ParameterShape::conversion_cost`], so an overload matched via exact shapes and
+        /// types costs 0 and one reached only through implicit promotion or a generic shape
+        /// parameter costs progressively more. A fully generic instance (no prototype) always
+        /// costs 0, since it accepts anything by construction.
+        pub fn conversion_cost(&self, params: &[(BaseTypeShape, ParameterBaseType)]) -> Option<u32> {
+            let Some(prototype) = &self.prototype else { return Some(0) };
+            if params.len() != prototype.len() {
+                return None;
+            }
+
+            let mut total = 0u32;
+            for ((size, base_type), proto) in params.iter().zip(prototype.iter()) {
+                if !proto.shape.matches(*size) {
+                    return None;
+                }
+                total += base_type.conversion_cost(&proto.base_type)? + proto.shape.conversion_cost();
+            }
+            Some(total)
+        }
+
         /// Checks if the provided parameter types are compatible with this function prototype.
         /// This check includes implicit casting rules.
         ///
@@ -1407,61 +2181,19 @@ mod function {
         /// \[(Vec2, Int)] or \[(Vec2, UInt)] returns true while calling it with \[(Vec2, Bool)]
         /// returns false.
         pub fn compatible_with(&self, params: &[(BaseTypeShape, ParameterBaseType)]) -> bool {
-            if let Some(prototype) = &self.prototype {
-                if params.len() != prototype.len() {
-                    return false;
-                }
-
-                for ((size, base_type), proto) in params.iter().zip(prototype.iter()) {
-                    if !proto.shape.matches(*size) {
-                        return false;
-                    }
-                    if !base_type.can_cast_into(&proto.base_type) {
-                        return false;
-                    }
-                }
-                true
-            } else {
-                true
-            }
+            self.conversion_cost(params).is_some()
         }
 
-        /// Evaluates this function for the provided parameters performing implicit casting if
+        /// Evaluates this function for the provided parameters, performing implicit casting if
         /// necessary.
         ///
-        /// # Panics
-        /// If the provided parameters cannot be implicitly cast to the required type. Check
-        /// compatibility with [Overload::compatible_with] first if needed.
-        pub fn eval(&self, params: &[&ConstBaseVal]) -> Option<ConstBaseVal> {
+        /// Returns [`ConstEvalError::ParameterCountMismatch`] or
+        /// [`ConstEvalError::ImplicitCastFailed`] instead of panicking if the parameters do not
+        /// actually match this overload's prototype; check compatibility with
+        /// [`ConstEvalFunctionInstance::compatible_with`] first to avoid relying on this.
+        pub fn eval(&self, params: &[&ConstBaseVal]) -> Result<Option<ConstBaseVal>, ConstEvalError> {
             (self.function)(params)
         }
-
-        /// Provides a order sorting functions by prototype specificity and casting order.
-        ///
-        /// The practical goal is that if a list of functions is sorted by this order then one can
-        /// iterate this list in ascending order and the first function compatible with the provided
-        /// parameters will also be the best matching function.
-        pub fn cast_cmp(&self, other: &Self) -> Ordering {
-            match (&self.prototype, &other.prototype) {
-                (Some(p1), Some(p2)) => {
-                    let len_cmp = p1.len().cmp(&p2.len());
-                    if len_cmp == Ordering::Equal {
-                        p1.iter().zip(p2.iter()).fold(Ordering::Equal, |i, (a, b)| {
-                            if i == Ordering::Equal {
-                                a.cast_cmp(b)
-                            } else {
-                                i
-                            }
-                        })
-                    } else {
-                        len_cmp
-                    }
-                },
-                (None, Some(_)) => Ordering::Greater,
-                (Some(_), None) => Ordering::Less,
-                (None, None) => Ordering::Equal,
-            }
-        }
     }
 
     pub struct ConstEvalFunctionBuilder {
@@ -1488,11 +2220,10 @@ mod function {
 
         /// Adds an overload to this function taking 1 parameter.
         ///
-        /// If the provided function returns [`None`] when evaluated it is not interpreted as an
-        /// error but indicates that the parameters do not match the function prototype (for example
-        /// when using generic sized vectors/matrices). The [ConstEvalFunction::eval] method will
-        /// not immediately return but continue searching for a matching overload if a function
-        /// returns [`None`].
+        /// If the provided function returns [`None`] when evaluated despite the parameters
+        /// matching this overload's prototype, [`ConstEvalFunction::eval`] treats the call as
+        /// declined and reports no matching overload, it does not fall back to a different
+        /// overload.
         pub fn add_overload_1<R, T0, F>(mut self, f: F) -> Self where R: ConstParameter, T0: ConstParameter + 'static, F: Fn(T0) -> Option<R> + Send + Sync + 'static {
             self.overloads.push(ConstEvalFunctionInstance::from_fn_1(f));
             self
@@ -1500,19 +2231,27 @@ mod function {
 
         /// Adds an overload to this function taking 2 parameter.
         ///
-        /// If the provided function returns [`None`] when evaluated it is not interpreted as an
-        /// error but indicates that the parameters do not match the function prototype (for example
-        /// when using generic sized vectors/matrices). The [ConstEvalFunction::eval] method will
-        /// not immediately return but continue searching for a matching overload if a function
-        /// returns [`None`].
+        /// If the provided function returns [`None`] when evaluated despite the parameters
+        /// matching this overload's prototype, [`ConstEvalFunction::eval`] treats the call as
+        /// declined and reports no matching overload, it does not fall back to a different
+        /// overload.
         pub fn add_overload_2<R, T0, T1, F>(mut self, f: F) -> Self where R: ConstParameter, T0: ConstParameter + 'static, T1: ConstParameter + 'static, F: Fn(T0, T1) -> Option<R> + Send + Sync + 'static {
             self.overloads.push(ConstEvalFunctionInstance::from_fn_2(f));
             self
         }
 
-        pub fn build(mut self) -> ConstEvalFunction {
-            self.overloads.sort_by(ConstEvalFunctionInstance::cast_cmp);
+        /// Adds an overload to this function taking 3 parameter.
+        ///
+        /// If the provided function returns [`None`] when evaluated despite the parameters
+        /// matching this overload's prototype, [`ConstEvalFunction::eval`] treats the call as
+        /// declined and reports no matching overload, it does not fall back to a different
+        /// overload.
+        pub fn add_overload_3<R, T0, T1, T2, F>(mut self, f: F) -> Self where R: ConstParameter, T0: ConstParameter + 'static, T1: ConstParameter + 'static, T2: ConstParameter + 'static, F: Fn(T0, T1, T2) -> Option<R> + Send + Sync + 'static {
+            self.overloads.push(ConstEvalFunctionInstance::from_fn_3(f));
+            self
+        }
 
+        pub fn build(self) -> ConstEvalFunction {
             ConstEvalFunction {
                 overloads: self.overloads.into_boxed_slice(),
             }
@@ -1524,22 +2263,44 @@ mod function {
     }
 
     impl ConstEvalFunction {
-        /// Evaluates the function on the provided parameters. Returns [`None`] if no matching
-        /// overload could be found.
-        pub fn eval(&self, params: &[&ConstBaseVal]) -> Option<ConstBaseVal> {
+        /// Evaluates the function on the provided parameters, picking the best-matching overload
+        /// by GLSL-style conversion-cost ranking rather than the first one that happens to be
+        /// compatible.
+        ///
+        /// Every compatible overload's [`ConstEvalFunctionInstance::conversion_cost`] is computed
+        /// and the overload(s) with the lowest total cost are kept. If exactly one overload
+        /// achieves the minimum, it is evaluated; returns `Ok(None)` if no overload is compatible
+        /// at all, or if the best-matching overload declines at runtime (e.g. its generic
+        /// prototype does not actually apply). If two or more distinct overloads tie for the
+        /// minimum cost, the call is ambiguous and
+        /// [`ConstEvalError::AmbiguousFunctionOverload`] is returned, matching how a real GLSL
+        /// compiler rejects an ambiguous overload resolution instead of arbitrarily picking one.
+        pub fn eval(&self, params: &[&ConstBaseVal]) -> Result<Option<ConstBaseVal>, ConstEvalError> {
             let mut types = Vec::with_capacity(params.len());
             for param in params {
                 types.push((param.get_shape(), ParameterBaseType::from_const_val(param)));
             }
 
+            let mut best: Option<&ConstEvalFunctionInstance> = None;
+            let mut best_cost = u32::MAX;
+            let mut ambiguous = false;
             for func in self.overloads.iter() {
-                if func.compatible_with(&types) {
-                    if let Some(result) = func.eval(params) {
-                        return Some(result);
+                if let Some(cost) = func.conversion_cost(&types) {
+                    if cost < best_cost {
+                        best = Some(func);
+                        best_cost = cost;
+                        ambiguous = false;
+                    } else if cost == best_cost {
+                        ambiguous = true;
                     }
                 }
-            };
-            None
+            }
+
+            match best {
+                Some(_) if ambiguous => Err(ConstEvalError::AmbiguousFunctionOverload),
+                Some(func) => func.eval(params),
+                None => Ok(None),
+            }
         }
     }
 
@@ -1715,6 +2476,45 @@ mod function {
     const_param_double!(ConstSVVal<f64>, ParameterShape::GenericSV);
     const_param_double!(ConstSVMVal<f64>, ParameterShape::GenericSVM);
 
+    macro_rules! const_param_half {
+        ($ty:ty, $ps:expr) => {
+            impl ConstParameter for $ty {
+                fn get_type() -> ParameterType {
+                    ParameterType::new(ParameterBaseType::Half, $ps)
+                }
+
+                fn try_cast_from(val: &ConstBaseVal) -> Option<Self> {
+                    match val {
+                        ConstBaseVal::Int(v) => ConstSVMVal::from(v.map(|v| f16::construct_from(&v))).try_into().ok(),
+                        ConstBaseVal::UInt(v) => ConstSVMVal::from(v.map(|v| f16::construct_from(&v))).try_into().ok(),
+                        ConstBaseVal::Float16(v) => v.clone().try_into().ok(),
+                        _ => None,
+                    }
+                }
+
+                fn into_const_base_val(self) -> ConstBaseVal {
+                    ConstBaseVal::Float16(self.into())
+                }
+            }
+        };
+    }
+    const_param_half!(f16, ParameterShape::Scalar);
+    const_param_half!(Vector2<f16>, ParameterShape::Vec2);
+    const_param_half!(Vector3<f16>, ParameterShape::Vec3);
+    const_param_half!(Vector4<f16>, ParameterShape::Vec4);
+    const_param_half!(Matrix2<f16>, ParameterShape::Mat2);
+    const_param_half!(Matrix2x3<f16>, ParameterShape::Mat23);
+    const_param_half!(Matrix2x4<f16>, ParameterShape::Mat24);
+    const_param_half!(Matrix3x2<f16>, ParameterShape::Mat32);
+    const_param_half!(Matrix3<f16>, ParameterShape::Mat3);
+    const_param_half!(Matrix3x4<f16>, ParameterShape::Mat34);
+    const_param_half!(Matrix4x2<f16>, ParameterShape::Mat42);
+    const_param_half!(Matrix4x3<f16>, ParameterShape::Mat43);
+    const_param_half!(Matrix4<f16>, ParameterShape::Mat4);
+    const_param_half!(ConstMVal<f16>, ParameterShape::GenericM);
+    const_param_half!(ConstSVVal<f16>, ParameterShape::GenericSV);
+    const_param_half!(ConstSVMVal<f16>, ParameterShape::GenericSVM);
+
     trait ScalarConstructFrom<T> {
         fn construct_from(from: &T) -> Self;
     }
@@ -1869,6 +2669,74 @@ mod function {
         }
     }
 
+    impl ScalarConstructFrom<bool> for f16 {
+        fn construct_from(from: &bool) -> f16 {
+            if *from { f16::from_f32(1f32) } else { f16::from_f32(0f32) }
+        }
+    }
+
+    impl ScalarConstructFrom<i32> for f16 {
+        fn construct_from(from: &i32) -> f16 {
+            f16::from_f32(*from as f32)
+        }
+    }
+
+    impl ScalarConstructFrom<u32> for f16 {
+        fn construct_from(from: &u32) -> f16 {
+            f16::from_f32(*from as f32)
+        }
+    }
+
+    // `half::f16::from_f32`/`from_f64` round to nearest, ties to even, matching GLSL's required
+    // narrowing behavior when a `float`/`double` constant is folded into a `float16_t`.
+    impl ScalarConstructFrom<f32> for f16 {
+        fn construct_from(from: &f32) -> f16 {
+            f16::from_f32(*from)
+        }
+    }
+
+    impl ScalarConstructFrom<f64> for f16 {
+        fn construct_from(from: &f64) -> f16 {
+            f16::from_f64(*from)
+        }
+    }
+
+    impl ScalarConstructFrom<f16> for f16 {
+        fn construct_from(from: &f16) -> f16 {
+            *from
+        }
+    }
+
+    impl ScalarConstructFrom<f16> for bool {
+        fn construct_from(from: &f16) -> bool {
+            from.to_f32() != 0f32
+        }
+    }
+
+    impl ScalarConstructFrom<f16> for i32 {
+        fn construct_from(from: &f16) -> i32 {
+            from.to_f32() as i32
+        }
+    }
+
+    impl ScalarConstructFrom<f16> for u32 {
+        fn construct_from(from: &f16) -> u32 {
+            from.to_f32() as u32
+        }
+    }
+
+    impl ScalarConstructFrom<f16> for f32 {
+        fn construct_from(from: &f16) -> f32 {
+            from.to_f32()
+        }
+    }
+
+    impl ScalarConstructFrom<f16> for f64 {
+        fn construct_from(from: &f16) -> f64 {
+            from.to_f64()
+        }
+    }
+
     fn add_sv_binop_components<T, F>(mut func: ConstEvalFunctionBuilder, f: F) -> ConstEvalFunctionBuilder where F: Fn(T, T) -> T + Clone + Send + Sync + 'static, T: ConstParameter + Scalar, ConstSVVal<T>: ConstParameter {
         let fc = f.clone();
         func = func.add_overload_2(move |a: ConstSVVal<T>, b: T| Some(a.map(|v| fc(v, b.clone()))));
@@ -1886,6 +2754,43 @@ mod function {
         add_sv_binop_components(func, f)
     }
 
+    /// Flattens a componentwise-optional value back into a single [`Option`], declining (returning
+    /// [`None`]) if any individual component declined, instead of silently dropping it. Used by
+    /// binary operators whose per-component closure can decline to fold (integer division/modulo
+    /// by zero, out-of-range shifts) without losing track of the surrounding vector shape.
+    fn sequence_sv<T: Scalar>(v: ConstSVVal<Option<T>>) -> Option<ConstSVVal<T>> {
+        match v {
+            ConstSVVal::Scalar(v) => v.map(ConstSVVal::Scalar),
+            ConstSVVal::Vector(ConstVVal::Vec2(v)) => Some(ConstSVVal::new_vec2(Vector2::new(v.x.clone()?, v.y.clone()?))),
+            ConstSVVal::Vector(ConstVVal::Vec3(v)) => Some(ConstSVVal::new_vec3(Vector3::new(v.x.clone()?, v.y.clone()?, v.z.clone()?))),
+            ConstSVVal::Vector(ConstVVal::Vec4(v)) => Some(ConstSVVal::new_vec4(Vector4::new(v.x.clone()?, v.y.clone()?, v.z.clone()?, v.w.clone()?))),
+        }
+    }
+
+    /// Like [`add_sv_binop_components`] but for operators that can decline to fold a single
+    /// component (e.g. checked integer division/modulo), returning `None` for the whole value
+    /// rather than panicking or propagating a poisoned component. Also registers an exact
+    /// `(T, T)` overload, so two scalars resolve to it instead of tying between the
+    /// scalar/vector overloads below.
+    fn add_checked_sv_binop_components<T, F>(mut func: ConstEvalFunctionBuilder, f: F) -> ConstEvalFunctionBuilder where F: Fn(T, T) -> Option<T> + Clone + Send + Sync + 'static, T: ConstParameter + Scalar, ConstSVVal<T>: ConstParameter {
+        let fc = f.clone();
+        func = func.add_overload_2(move |a: T, b: T| fc(a, b));
+        let fc = f.clone();
+        func = func.add_overload_2(move |a: ConstSVVal<T>, b: T| sequence_sv(a.map(|v| fc(v, b.clone()))));
+        let fc = f.clone();
+        func = func.add_overload_2(move |a: T, b: ConstSVVal<T>| sequence_sv(b.map(|v| fc(a.clone(), v))));
+        let fc = f.clone();
+        func.add_overload_2(move |a: ConstSVVal<T>, b: ConstSVVal<T>| a.zip_map(&b, move |x, y| fc(x, y)).and_then(sequence_sv))
+    }
+
+    fn add_checked_i32_binop_components<F>(func: ConstEvalFunctionBuilder, f: F) -> ConstEvalFunctionBuilder where F: Fn(i32, i32) -> Option<i32> + Clone + Send + Sync + 'static {
+        add_checked_sv_binop_components(func, f)
+    }
+
+    fn add_checked_u32_binop_components<F>(func: ConstEvalFunctionBuilder, f: F) -> ConstEvalFunctionBuilder where F: Fn(u32, u32) -> Option<u32> + Clone + Send + Sync + 'static {
+        add_checked_sv_binop_components(func, f)
+    }
+
     fn add_svm_binop_components<T, F>(mut func: ConstEvalFunctionBuilder, f: F) -> ConstEvalFunctionBuilder where F: Fn(T, T) -> T + Clone + Send + Sync + 'static, T: ConstParameter + Scalar, ConstSVVal<T>: ConstParameter, ConstMVal<T>: ConstParameter {
         func = add_sv_binop_components(func, f.clone());
         let fc = f.clone();
@@ -1904,6 +2809,100 @@ mod function {
         add_svm_binop_components(func, f)
     }
 
+    fn add_f16_binop_components<F>(func: ConstEvalFunctionBuilder, f: F) -> ConstEvalFunctionBuilder where F: Fn(f16, f16) -> f16 + Clone + Send + Sync + 'static {
+        add_svm_binop_components(func, f)
+    }
+
+    fn add_sv_unop_components<T, F>(func: ConstEvalFunctionBuilder, f: F) -> ConstEvalFunctionBuilder where F: Fn(T) -> T + Clone + Send + Sync + 'static, T: ConstParameter + Scalar, ConstSVVal<T>: ConstParameter {
+        func.add_overload_1(move |v: ConstSVVal<T>| Some(v.map(f.clone())))
+    }
+
+    fn add_f32_unop_components<F>(func: ConstEvalFunctionBuilder, f: F) -> ConstEvalFunctionBuilder where F: Fn(f32) -> f32 + Clone + Send + Sync + 'static {
+        add_sv_unop_components(func, f)
+    }
+
+    fn add_f64_unop_components<F>(func: ConstEvalFunctionBuilder, f: F) -> ConstEvalFunctionBuilder where F: Fn(f64) -> f64 + Clone + Send + Sync + 'static {
+        add_sv_unop_components(func, f)
+    }
+
+    fn add_i32_unop_components<F>(func: ConstEvalFunctionBuilder, f: F) -> ConstEvalFunctionBuilder where F: Fn(i32) -> i32 + Clone + Send + Sync + 'static {
+        add_sv_unop_components(func, f)
+    }
+
+    /// Applies `f` componentwise across three [`ConstSVVal`]s of matching shape, analogous to
+    /// [`ConstGenericZipMappable::zip_map`] but for three operands instead of two (no ternary
+    /// combinator exists on the generic value traits, so this chains two binary zips through a
+    /// tuple intermediate).
+    fn zip_map3_sv<T, F>(a: &ConstSVVal<T>, b: &ConstSVVal<T>, c: &ConstSVVal<T>, mut f: F) -> Option<ConstSVVal<T>> where T: Scalar, F: FnMut(T, T, T) -> T {
+        let ab = a.zip_map(b, |x, y| (x, y))?;
+        ab.zip_map(c, move |(x, y), z| f(x, y, z))
+    }
+
+    fn add_sv_clamp_components<T, F>(mut func: ConstEvalFunctionBuilder, f: F) -> ConstEvalFunctionBuilder where F: Fn(T, T, T) -> T + Clone + Send + Sync + 'static, T: ConstParameter + Scalar, ConstSVVal<T>: ConstParameter {
+        let fc = f.clone();
+        func = func.add_overload_3(move |x: ConstSVVal<T>, min_val: T, max_val: T| Some(x.map(|v| fc(v, min_val.clone(), max_val.clone()))));
+        let fc = f.clone();
+        func.add_overload_3(move |x: ConstSVVal<T>, min_val: ConstSVVal<T>, max_val: ConstSVVal<T>| zip_map3_sv(&x, &min_val, &max_val, &fc))
+    }
+
+    fn add_i32_clamp_components<F>(func: ConstEvalFunctionBuilder, f: F) -> ConstEvalFunctionBuilder where F: Fn(i32, i32, i32) -> i32 + Clone + Send + Sync + 'static {
+        add_sv_clamp_components(func, f)
+    }
+
+    fn add_u32_clamp_components<F>(func: ConstEvalFunctionBuilder, f: F) -> ConstEvalFunctionBuilder where F: Fn(u32, u32, u32) -> u32 + Clone + Send + Sync + 'static {
+        add_sv_clamp_components(func, f)
+    }
+
+    fn add_f32_clamp_components<F>(func: ConstEvalFunctionBuilder, f: F) -> ConstEvalFunctionBuilder where F: Fn(f32, f32, f32) -> f32 + Clone + Send + Sync + 'static {
+        add_sv_clamp_components(func, f)
+    }
+
+    fn add_f64_clamp_components<F>(func: ConstEvalFunctionBuilder, f: F) -> ConstEvalFunctionBuilder where F: Fn(f64, f64, f64) -> f64 + Clone + Send + Sync + 'static {
+        add_sv_clamp_components(func, f)
+    }
+
+    fn add_sv_mix_components<T, F>(mut func: ConstEvalFunctionBuilder, f: F) -> ConstEvalFunctionBuilder where F: Fn(T, T, T) -> T + Clone + Send + Sync + 'static, T: ConstParameter + Scalar, ConstSVVal<T>: ConstParameter {
+        let fc = f.clone();
+        func = func.add_overload_3(move |x: ConstSVVal<T>, y: ConstSVVal<T>, a: T| x.zip_map(&y, |xv, yv| fc(xv, yv, a.clone())));
+        let fc = f.clone();
+        func.add_overload_3(move |x: ConstSVVal<T>, y: ConstSVVal<T>, a: ConstSVVal<T>| zip_map3_sv(&x, &y, &a, &fc))
+    }
+
+    fn add_f32_mix_components<F>(func: ConstEvalFunctionBuilder, f: F) -> ConstEvalFunctionBuilder where F: Fn(f32, f32, f32) -> f32 + Clone + Send + Sync + 'static {
+        add_sv_mix_components(func, f)
+    }
+
+    fn add_f64_mix_components<F>(func: ConstEvalFunctionBuilder, f: F) -> ConstEvalFunctionBuilder where F: Fn(f64, f64, f64) -> f64 + Clone + Send + Sync + 'static {
+        add_sv_mix_components(func, f)
+    }
+
+    fn add_sv_smoothstep_components<T, F>(mut func: ConstEvalFunctionBuilder, f: F) -> ConstEvalFunctionBuilder where F: Fn(T, T, T) -> T + Clone + Send + Sync + 'static, T: ConstParameter + Scalar, ConstSVVal<T>: ConstParameter {
+        let fc = f.clone();
+        func = func.add_overload_3(move |edge0: T, edge1: T, x: ConstSVVal<T>| Some(x.map(|v| fc(edge0.clone(), edge1.clone(), v))));
+        let fc = f.clone();
+        func.add_overload_3(move |edge0: ConstSVVal<T>, edge1: ConstSVVal<T>, x: ConstSVVal<T>| zip_map3_sv(&edge0, &edge1, &x, &fc))
+    }
+
+    fn add_f32_smoothstep_components<F>(func: ConstEvalFunctionBuilder, f: F) -> ConstEvalFunctionBuilder where F: Fn(f32, f32, f32) -> f32 + Clone + Send + Sync + 'static {
+        add_sv_smoothstep_components(func, f)
+    }
+
+    fn add_f64_smoothstep_components<F>(func: ConstEvalFunctionBuilder, f: F) -> ConstEvalFunctionBuilder where F: Fn(f64, f64, f64) -> f64 + Clone + Send + Sync + 'static {
+        add_sv_smoothstep_components(func, f)
+    }
+
+    fn add_sv_fma_components<T, F>(func: ConstEvalFunctionBuilder, f: F) -> ConstEvalFunctionBuilder where F: Fn(T, T, T) -> T + Clone + Send + Sync + 'static, T: ConstParameter + Scalar, ConstSVVal<T>: ConstParameter {
+        func.add_overload_3(move |a: ConstSVVal<T>, b: ConstSVVal<T>, c: ConstSVVal<T>| zip_map3_sv(&a, &b, &c, &f))
+    }
+
+    fn add_f32_fma_components<F>(func: ConstEvalFunctionBuilder, f: F) -> ConstEvalFunctionBuilder where F: Fn(f32, f32, f32) -> f32 + Clone + Send + Sync + 'static {
+        add_sv_fma_components(func, f)
+    }
+
+    fn add_f64_fma_components<F>(func: ConstEvalFunctionBuilder, f: F) -> ConstEvalFunctionBuilder where F: Fn(f64, f64, f64) -> f64 + Clone + Send + Sync + 'static {
+        add_sv_fma_components(func, f)
+    }
+
     lazy_static! {
         pub static ref OP_UNARY_ADD: ConstEvalFunction = {
             ConstEvalFunctionBuilder::new()
@@ -1911,6 +2910,7 @@ mod function {
                 .add_overload_1(|v: ConstSVVal<u32>| Some(v))
                 .add_overload_1(|v: ConstSVMVal<f32>| Some(v))
                 .add_overload_1(|v: ConstSVMVal<f64>| Some(v))
+                .add_overload_1(|v: ConstSVMVal<f16>| Some(v))
                 .build()
         };
         pub static ref OP_UNARY_MINUS: ConstEvalFunction = {
@@ -1919,6 +2919,7 @@ mod function {
                 .add_overload_1(|v: ConstSVVal<u32>| Some(v.map(u32::wrapping_neg)))
                 .add_overload_1(|v: ConstSVMVal<f32>| Some(v.map(f32::neg)))
                 .add_overload_1(|v: ConstSVMVal<f64>| Some(v.map(f64::neg)))
+                .add_overload_1(|v: ConstSVMVal<f16>| Some(v.map(|v| -v)))
                 .build()
         };
         pub static ref OP_UNARY_NOT: ConstEvalFunction = {
@@ -1972,6 +2973,7 @@ mod function {
                 .add_overload_2(|a: ConstSVVal<u32>, b: ConstSVVal<u32>| Some(a.zip_map(&b, |a, b| a == b)?.fold(true, bool::bitand)))
                 .add_overload_2(|a: ConstSVMVal<f32>, b: ConstSVMVal<f32>| Some(a.zip_map(&b, |a, b| a == b)?.fold(true, bool::bitand)))
                 .add_overload_2(|a: ConstSVMVal<f64>, b: ConstSVMVal<f64>| Some(a.zip_map(&b, |a, b| a == b)?.fold(true, bool::bitand)))
+                .add_overload_2(|a: ConstSVMVal<f16>, b: ConstSVMVal<f16>| Some(a.zip_map(&b, |a, b| a == b)?.fold(true, bool::bitand)))
                 .build()
         };
         pub static ref OP_BINARY_LT: ConstEvalFunction = {
@@ -1980,6 +2982,7 @@ mod function {
                 .add_overload_2(|a: u32, b: u32| Some(a < b))
                 .add_overload_2(|a: f32, b: f32| Some(a < b))
                 .add_overload_2(|a: f64, b: f64| Some(a < b))
+                .add_overload_2(|a: f16, b: f16| Some(a < b))
                 .build()
         };
         pub static ref OP_BINARY_GT: ConstEvalFunction = {
@@ -1988,6 +2991,7 @@ mod function {
                 .add_overload_2(|a: u32, b: u32| Some(a > b))
                 .add_overload_2(|a: f32, b: f32| Some(a > b))
                 .add_overload_2(|a: f64, b: f64| Some(a > b))
+                .add_overload_2(|a: f16, b: f16| Some(a > b))
                 .build()
         };
         pub static ref OP_BINARY_LTE: ConstEvalFunction = {
@@ -1996,6 +3000,7 @@ mod function {
                 .add_overload_2(|a: u32, b: u32| Some(a <= b))
                 .add_overload_2(|a: f32, b: f32| Some(a <= b))
                 .add_overload_2(|a: f64, b: f64| Some(a <= b))
+                .add_overload_2(|a: f16, b: f16| Some(a <= b))
                 .build()
         };
         pub static ref OP_BINARY_GTE: ConstEvalFunction = {
@@ -2004,30 +3009,34 @@ mod function {
                 .add_overload_2(|a: u32, b: u32| Some(a >= b))
                 .add_overload_2(|a: f32, b: f32| Some(a >= b))
                 .add_overload_2(|a: f64, b: f64| Some(a >= b))
+                .add_overload_2(|a: f16, b: f16| Some(a >= b))
                 .build()
         };
+        // Shift amounts outside `0..32` are undefined in GLSL; `checked_shl`/`checked_shr` decline
+        // (return `None`) rather than panicking or masking the amount, which `sequence_sv` then
+        // propagates as "not a compile-time constant" for the whole value.
         pub static ref OP_BINARY_LSHIFT: ConstEvalFunction = {
             ConstEvalFunctionBuilder::new()
-                .add_overload_2(|a: ConstSVVal<i32>, b: i32| Some(a.map(|v| v << b)))
-                .add_overload_2(|a: ConstSVVal<i32>, b: u32| Some(a.map(|v| v << b)))
-                .add_overload_2(|a: ConstSVVal<i32>, b: ConstSVVal<i32>| a.zip_map(&b, |a, b| a << b))
-                .add_overload_2(|a: ConstSVVal<i32>, b: ConstSVVal<u32>| a.zip_map(&b, |a, b| a << b))
-                .add_overload_2(|a: ConstSVVal<u32>, b: i32| Some(a.map(|v| v << b)))
-                .add_overload_2(|a: ConstSVVal<u32>, b: u32| Some(a.map(|v| v << b)))
-                .add_overload_2(|a: ConstSVVal<u32>, b: ConstSVVal<i32>| a.zip_map(&b, |a, b| a << b))
-                .add_overload_2(|a: ConstSVVal<u32>, b: ConstSVVal<u32>| a.zip_map(&b, |a, b| a << b))
+                .add_overload_2(|a: ConstSVVal<i32>, b: i32| sequence_sv(a.map(|v| v.checked_shl(b as u32))))
+                .add_overload_2(|a: ConstSVVal<i32>, b: u32| sequence_sv(a.map(|v| v.checked_shl(b))))
+                .add_overload_2(|a: ConstSVVal<i32>, b: ConstSVVal<i32>| a.zip_map(&b, |a, b| a.checked_shl(b as u32)).and_then(sequence_sv))
+                .add_overload_2(|a: ConstSVVal<i32>, b: ConstSVVal<u32>| a.zip_map(&b, |a, b| a.checked_shl(b)).and_then(sequence_sv))
+                .add_overload_2(|a: ConstSVVal<u32>, b: i32| sequence_sv(a.map(|v| v.checked_shl(b as u32))))
+                .add_overload_2(|a: ConstSVVal<u32>, b: u32| sequence_sv(a.map(|v| v.checked_shl(b))))
+                .add_overload_2(|a: ConstSVVal<u32>, b: ConstSVVal<i32>| a.zip_map(&b, |a, b| a.checked_shl(b as u32)).and_then(sequence_sv))
+                .add_overload_2(|a: ConstSVVal<u32>, b: ConstSVVal<u32>| a.zip_map(&b, |a, b| a.checked_shl(b)).and_then(sequence_sv))
                 .build()
         };
         pub static ref OP_BINARY_RSHIFT: ConstEvalFunction = {
             ConstEvalFunctionBuilder::new()
-                .add_overload_2(|a: ConstSVVal<i32>, b: i32| Some(a.map(|v| v >> b)))
-                .add_overload_2(|a: ConstSVVal<i32>, b: u32| Some(a.map(|v| v >> b)))
-                .add_overload_2(|a: ConstSVVal<i32>, b: ConstSVVal<i32>| a.zip_map(&b, |a, b| a >> b))
-                .add_overload_2(|a: ConstSVVal<i32>, b: ConstSVVal<u32>| a.zip_map(&b, |a, b| a >> b))
-                .add_overload_2(|a: ConstSVVal<u32>, b: i32| Some(a.map(|v| v >> b)))
-                .add_overload_2(|a: ConstSVVal<u32>, b: u32| Some(a.map(|v| v >> b)))
-                .add_overload_2(|a: ConstSVVal<u32>, b: ConstSVVal<i32>| a.zip_map(&b, |a, b| a >> b))
-                .add_overload_2(|a: ConstSVVal<u32>, b: ConstSVVal<u32>| a.zip_map(&b, |a, b| a >> b))
+                .add_overload_2(|a: ConstSVVal<i32>, b: i32| sequence_sv(a.map(|v| v.checked_shr(b as u32))))
+                .add_overload_2(|a: ConstSVVal<i32>, b: u32| sequence_sv(a.map(|v| v.checked_shr(b))))
+                .add_overload_2(|a: ConstSVVal<i32>, b: ConstSVVal<i32>| a.zip_map(&b, |a, b| a.checked_shr(b as u32)).and_then(sequence_sv))
+                .add_overload_2(|a: ConstSVVal<i32>, b: ConstSVVal<u32>| a.zip_map(&b, |a, b| a.checked_shr(b)).and_then(sequence_sv))
+                .add_overload_2(|a: ConstSVVal<u32>, b: i32| sequence_sv(a.map(|v| v.checked_shr(b as u32))))
+                .add_overload_2(|a: ConstSVVal<u32>, b: u32| sequence_sv(a.map(|v| v.checked_shr(b))))
+                .add_overload_2(|a: ConstSVVal<u32>, b: ConstSVVal<i32>| a.zip_map(&b, |a, b| a.checked_shr(b as u32)).and_then(sequence_sv))
+                .add_overload_2(|a: ConstSVVal<u32>, b: ConstSVVal<u32>| a.zip_map(&b, |a, b| a.checked_shr(b)).and_then(sequence_sv))
                 .build()
         };
         pub static ref OP_BINARY_ADD: ConstEvalFunction = {
@@ -2036,6 +3045,7 @@ mod function {
             f = add_u32_binop_components(f, |a, b| a + b);
             f = add_f32_binop_components(f, |a, b| a + b);
             f = add_f64_binop_components(f, |a, b| a + b);
+            f = add_f16_binop_components(f, |a, b| a + b);
             f.build()
         };
         pub static ref OP_BINARY_SUB: ConstEvalFunction = {
@@ -2044,6 +3054,7 @@ mod function {
             f = add_u32_binop_components(f, |a, b| a - b);
             f = add_f32_binop_components(f, |a, b| a - b);
             f = add_f64_binop_components(f, |a, b| a - b);
+            f = add_f16_binop_components(f, |a, b| a - b);
             f.build()
         };
         pub static ref OP_BINARY_MULT: ConstEvalFunction = {
@@ -2052,6 +3063,7 @@ mod function {
             f = add_u32_binop_components(f, |a, b| a * b);
             f = add_sv_binop_components(f, |a: f32, b: f32| a * b);
             f = add_sv_binop_components(f, |a: f64, b: f64| a * b);
+            f = add_sv_binop_components(f, |a: f16, b: f16| a * b);
             f.add_overload_2(|a: Vector2<f32>, b: Matrix2<f32>| Some((a.transpose() * b).transpose()))
                 .add_overload_2(|a: Vector2<f32>, b: Matrix2x3<f32>| Some((a.transpose() * b).transpose()))
                 .add_overload_2(|a: Vector2<f32>, b: Matrix2x4<f32>| Some((a.transpose() * b).transpose()))
@@ -2088,41 +3100,528 @@ mod function {
                 .add_overload_2(|a: Matrix2x4<f64>, b: Vector4<f64>| Some(a * b))
                 .add_overload_2(|a: Matrix3x4<f64>, b: Vector4<f64>| Some(a * b))
                 .add_overload_2(|a: Matrix4<f64>, b: Vector4<f64>| Some(a * b))
+                .add_overload_2(|a: Matrix2<f32>, b: f32| Some(a.map(|v| v * b)))
+                .add_overload_2(|a: f32, b: Matrix2<f32>| Some(b.map(|v| a * v)))
+                .add_overload_2(|a: Matrix2x3<f32>, b: f32| Some(a.map(|v| v * b)))
+                .add_overload_2(|a: f32, b: Matrix2x3<f32>| Some(b.map(|v| a * v)))
+                .add_overload_2(|a: Matrix2x4<f32>, b: f32| Some(a.map(|v| v * b)))
+                .add_overload_2(|a: f32, b: Matrix2x4<f32>| Some(b.map(|v| a * v)))
+                .add_overload_2(|a: Matrix3x2<f32>, b: f32| Some(a.map(|v| v * b)))
+                .add_overload_2(|a: f32, b: Matrix3x2<f32>| Some(b.map(|v| a * v)))
+                .add_overload_2(|a: Matrix3<f32>, b: f32| Some(a.map(|v| v * b)))
+                .add_overload_2(|a: f32, b: Matrix3<f32>| Some(b.map(|v| a * v)))
+                .add_overload_2(|a: Matrix3x4<f32>, b: f32| Some(a.map(|v| v * b)))
+                .add_overload_2(|a: f32, b: Matrix3x4<f32>| Some(b.map(|v| a * v)))
+                .add_overload_2(|a: Matrix4x2<f32>, b: f32| Some(a.map(|v| v * b)))
+                .add_overload_2(|a: f32, b: Matrix4x2<f32>| Some(b.map(|v| a * v)))
+                .add_overload_2(|a: Matrix4x3<f32>, b: f32| Some(a.map(|v| v * b)))
+                .add_overload_2(|a: f32, b: Matrix4x3<f32>| Some(b.map(|v| a * v)))
+                .add_overload_2(|a: Matrix4<f32>, b: f32| Some(a.map(|v| v * b)))
+                .add_overload_2(|a: f32, b: Matrix4<f32>| Some(b.map(|v| a * v)))
+                .add_overload_2(|a: Matrix2<f64>, b: f64| Some(a.map(|v| v * b)))
+                .add_overload_2(|a: f64, b: Matrix2<f64>| Some(b.map(|v| a * v)))
+                .add_overload_2(|a: Matrix2x3<f64>, b: f64| Some(a.map(|v| v * b)))
+                .add_overload_2(|a: f64, b: Matrix2x3<f64>| Some(b.map(|v| a * v)))
+                .add_overload_2(|a: Matrix2x4<f64>, b: f64| Some(a.map(|v| v * b)))
+                .add_overload_2(|a: f64, b: Matrix2x4<f64>| Some(b.map(|v| a * v)))
+                .add_overload_2(|a: Matrix3x2<f64>, b: f64| Some(a.map(|v| v * b)))
+                .add_overload_2(|a: f64, b: Matrix3x2<f64>| Some(b.map(|v| a * v)))
+                .add_overload_2(|a: Matrix3<f64>, b: f64| Some(a.map(|v| v * b)))
+                .add_overload_2(|a: f64, b: Matrix3<f64>| Some(b.map(|v| a * v)))
+                .add_overload_2(|a: Matrix3x4<f64>, b: f64| Some(a.map(|v| v * b)))
+                .add_overload_2(|a: f64, b: Matrix3x4<f64>| Some(b.map(|v| a * v)))
+                .add_overload_2(|a: Matrix4x2<f64>, b: f64| Some(a.map(|v| v * b)))
+                .add_overload_2(|a: f64, b: Matrix4x2<f64>| Some(b.map(|v| a * v)))
+                .add_overload_2(|a: Matrix4x3<f64>, b: f64| Some(a.map(|v| v * b)))
+                .add_overload_2(|a: f64, b: Matrix4x3<f64>| Some(b.map(|v| a * v)))
+                .add_overload_2(|a: Matrix4<f64>, b: f64| Some(a.map(|v| v * b)))
+                .add_overload_2(|a: f64, b: Matrix4<f64>| Some(b.map(|v| a * v)))
+                .add_overload_2(|a: Matrix2<f32>, b: Matrix2<f32>| Some(a * b))
+                .add_overload_2(|a: Matrix2x3<f32>, b: Matrix3x2<f32>| Some(a * b))
+                .add_overload_2(|a: Matrix2x4<f32>, b: Matrix4x2<f32>| Some(a * b))
+                .add_overload_2(|a: Matrix2<f32>, b: Matrix2x3<f32>| Some(a * b))
+                .add_overload_2(|a: Matrix2x3<f32>, b: Matrix3<f32>| Some(a * b))
+                .add_overload_2(|a: Matrix2x4<f32>, b: Matrix4x3<f32>| Some(a * b))
+                .add_overload_2(|a: Matrix2<f32>, b: Matrix2x4<f32>| Some(a * b))
+                .add_overload_2(|a: Matrix2x3<f32>, b: Matrix3x4<f32>| Some(a * b))
+                .add_overload_2(|a: Matrix2x4<f32>, b: Matrix4<f32>| Some(a * b))
+                .add_overload_2(|a: Matrix3x2<f32>, b: Matrix2<f32>| Some(a * b))
+                .add_overload_2(|a: Matrix3<f32>, b: Matrix3x2<f32>| Some(a * b))
+                .add_overload_2(|a: Matrix3x4<f32>, b: Matrix4x2<f32>| Some(a * b))
+                .add_overload_2(|a: Matrix3x2<f32>, b: Matrix2x3<f32>| Some(a * b))
+                .add_overload_2(|a: Matrix3<f32>, b: Matrix3<f32>| Some(a * b))
+                .add_overload_2(|a: Matrix3x4<f32>, b: Matrix4x3<f32>| Some(a * b))
+                .add_overload_2(|a: Matrix3x2<f32>, b: Matrix2x4<f32>| Some(a * b))
+                .add_overload_2(|a: Matrix3<f32>, b: Matrix3x4<f32>| Some(a * b))
+                .add_overload_2(|a: Matrix3x4<f32>, b: Matrix4<f32>| Some(a * b))
+                .add_overload_2(|a: Matrix4x2<f32>, b: Matrix2<f32>| Some(a * b))
+                .add_overload_2(|a: Matrix4x3<f32>, b: Matrix3x2<f32>| Some(a * b))
+                .add_overload_2(|a: Matrix4<f32>, b: Matrix4x2<f32>| Some(a * b))
+                .add_overload_2(|a: Matrix4x2<f32>, b: Matrix2x3<f32>| Some(a * b))
+                .add_overload_2(|a: Matrix4x3<f32>, b: Matrix3<f32>| Some(a * b))
+                .add_overload_2(|a: Matrix4<f32>, b: Matrix4x3<f32>| Some(a * b))
+                .add_overload_2(|a: Matrix4x2<f32>, b: Matrix2x4<f32>| Some(a * b))
+                .add_overload_2(|a: Matrix4x3<f32>, b: Matrix3x4<f32>| Some(a * b))
+                .add_overload_2(|a: Matrix4<f32>, b: Matrix4<f32>| Some(a * b))
+                .add_overload_2(|a: Matrix2<f64>, b: Matrix2<f64>| Some(a * b))
+                .add_overload_2(|a: Matrix2x3<f64>, b: Matrix3x2<f64>| Some(a * b))
+                .add_overload_2(|a: Matrix2x4<f64>, b: Matrix4x2<f64>| Some(a * b))
+                .add_overload_2(|a: Matrix2<f64>, b: Matrix2x3<f64>| Some(a * b))
+                .add_overload_2(|a: Matrix2x3<f64>, b: Matrix3<f64>| Some(a * b))
+                .add_overload_2(|a: Matrix2x4<f64>, b: Matrix4x3<f64>| Some(a * b))
+                .add_overload_2(|a: Matrix2<f64>, b: Matrix2x4<f64>| Some(a * b))
+                .add_overload_2(|a: Matrix2x3<f64>, b: Matrix3x4<f64>| Some(a * b))
+                .add_overload_2(|a: Matrix2x4<f64>, b: Matrix4<f64>| Some(a * b))
+                .add_overload_2(|a: Matrix3x2<f64>, b: Matrix2<f64>| Some(a * b))
+                .add_overload_2(|a: Matrix3<f64>, b: Matrix3x2<f64>| Some(a * b))
+                .add_overload_2(|a: Matrix3x4<f64>, b: Matrix4x2<f64>| Some(a * b))
+                .add_overload_2(|a: Matrix3x2<f64>, b: Matrix2x3<f64>| Some(a * b))
+                .add_overload_2(|a: Matrix3<f64>, b: Matrix3<f64>| Some(a * b))
+                .add_overload_2(|a: Matrix3x4<f64>, b: Matrix4x3<f64>| Some(a * b))
+                .add_overload_2(|a: Matrix3x2<f64>, b: Matrix2x4<f64>| Some(a * b))
+                .add_overload_2(|a: Matrix3<f64>, b: Matrix3x4<f64>| Some(a * b))
+                .add_overload_2(|a: Matrix3x4<f64>, b: Matrix4<f64>| Some(a * b))
+                .add_overload_2(|a: Matrix4x2<f64>, b: Matrix2<f64>| Some(a * b))
+                .add_overload_2(|a: Matrix4x3<f64>, b: Matrix3x2<f64>| Some(a * b))
+                .add_overload_2(|a: Matrix4<f64>, b: Matrix4x2<f64>| Some(a * b))
+                .add_overload_2(|a: Matrix4x2<f64>, b: Matrix2x3<f64>| Some(a * b))
+                .add_overload_2(|a: Matrix4x3<f64>, b: Matrix3<f64>| Some(a * b))
+                .add_overload_2(|a: Matrix4<f64>, b: Matrix4x3<f64>| Some(a * b))
+                .add_overload_2(|a: Matrix4x2<f64>, b: Matrix2x4<f64>| Some(a * b))
+                .add_overload_2(|a: Matrix4x3<f64>, b: Matrix3x4<f64>| Some(a * b))
+                .add_overload_2(|a: Matrix4<f64>, b: Matrix4<f64>| Some(a * b))
                 .build()
         };
+        // Integer division/modulo decline (via `checked_div`/`checked_rem`) on a zero divisor and
+        // on the `i32::MIN / -1` overflow case rather than panicking; float division still yields
+        // GLSL's defined `inf`/`nan` so it keeps using the unchecked `add_f*_binop_components`.
         pub static ref OP_BINARY_DIV: ConstEvalFunction = {
             let mut f = ConstEvalFunctionBuilder::new();
-            f = add_i32_binop_components(f, |a, b| a / b);
-            f = add_u32_binop_components(f, |a, b| a / b);
+            f = add_checked_i32_binop_components(f, i32::checked_div);
+            f = add_checked_u32_binop_components(f, u32::checked_div);
             f = add_f32_binop_components(f, |a, b| a / b);
             f = add_f64_binop_components(f, |a, b| a / b);
+            f = add_f16_binop_components(f, |a, b| a / b);
             f.build()
         };
         pub static ref OP_BINARY_MOD: ConstEvalFunction = {
             let mut f = ConstEvalFunctionBuilder::new();
-            f = add_i32_binop_components(f, |a, b| a % b);
-            f = add_u32_binop_components(f, |a, b| a % b);
+            f = add_checked_i32_binop_components(f, i32::checked_rem);
+            f = add_checked_u32_binop_components(f, u32::checked_rem);
             f.build()
         };
     }
 
-    fn add_scalar_constructor<T>(f: ConstEvalFunctionBuilder) -> ConstEvalFunctionBuilder where T: Scalar + ConstParameter + ScalarConstructFrom<bool> + ScalarConstructFrom<i32> + ScalarConstructFrom<u32> + ScalarConstructFrom<f32> + ScalarConstructFrom<f64> {
+    // The GLSL standard library functions that the spec permits in constant expressions.
+    fn std_abs() -> ConstEvalFunction {
+        let mut f = ConstEvalFunctionBuilder::new();
+        f = add_i32_unop_components(f, i32::abs);
+        f = add_f32_unop_components(f, f32::abs);
+        f = add_f64_unop_components(f, f64::abs);
+        f.build()
+    }
+    fn std_sign() -> ConstEvalFunction {
+        let mut f = ConstEvalFunctionBuilder::new();
+        f = add_i32_unop_components(f, i32::signum);
+        f = add_f32_unop_components(f, f32::signum);
+        f = add_f64_unop_components(f, f64::signum);
+        f.build()
+    }
+    fn std_floor() -> ConstEvalFunction {
+        let mut f = ConstEvalFunctionBuilder::new();
+        f = add_f32_unop_components(f, f32::floor);
+        f = add_f64_unop_components(f, f64::floor);
+        f.build()
+    }
+    fn std_ceil() -> ConstEvalFunction {
+        let mut f = ConstEvalFunctionBuilder::new();
+        f = add_f32_unop_components(f, f32::ceil);
+        f = add_f64_unop_components(f, f64::ceil);
+        f.build()
+    }
+    fn std_round() -> ConstEvalFunction {
+        let mut f = ConstEvalFunctionBuilder::new();
+        f = add_f32_unop_components(f, f32::round);
+        f = add_f64_unop_components(f, f64::round);
+        f.build()
+    }
+    fn std_trunc() -> ConstEvalFunction {
+        let mut f = ConstEvalFunctionBuilder::new();
+        f = add_f32_unop_components(f, f32::trunc);
+        f = add_f64_unop_components(f, f64::trunc);
+        f.build()
+    }
+    fn std_fract() -> ConstEvalFunction {
+        let mut f = ConstEvalFunctionBuilder::new();
+        f = add_f32_unop_components(f, f32::fract);
+        f = add_f64_unop_components(f, f64::fract);
+        f.build()
+    }
+    fn std_mod() -> ConstEvalFunction {
+        let mut f = ConstEvalFunctionBuilder::new();
+        // GLSL `mod(x, y) = x - y * floor(x / y)`, which differs from Rust's `%` (truncating
+        // remainder) in its sign for negative operands.
+        f = add_f32_binop_components(f, |x, y| x - y * (x / y).floor());
+        f = add_f64_binop_components(f, |x, y| x - y * (x / y).floor());
+        f.build()
+    }
+        // `min`/`max` default to Rust's `f32::min`/`f32::max`, which return the non-NaN operand if
+        // exactly one is NaN (IEEE 754 `minNum`/`maxNum`); the spec leaves the NaN case
+        // implementation-defined, so this is a deliberate choice rather than an oversight.
+    fn std_min() -> ConstEvalFunction {
+        let mut f = ConstEvalFunctionBuilder::new();
+        f = add_i32_binop_components(f, i32::min);
+        f = add_u32_binop_components(f, u32::min);
+        f = add_f32_binop_components(f, f32::min);
+        f = add_f64_binop_components(f, f64::min);
+        f.build()
+    }
+    fn std_max() -> ConstEvalFunction {
+        let mut f = ConstEvalFunctionBuilder::new();
+        f = add_i32_binop_components(f, i32::max);
+        f = add_u32_binop_components(f, u32::max);
+        f = add_f32_binop_components(f, f32::max);
+        f = add_f64_binop_components(f, f64::max);
+        f.build()
+    }
+    fn std_clamp() -> ConstEvalFunction {
+        let mut f = ConstEvalFunctionBuilder::new();
+        f = add_i32_clamp_components(f, |x, min_val, max_val| x.max(min_val).min(max_val));
+        f = add_u32_clamp_components(f, |x, min_val, max_val| x.max(min_val).min(max_val));
+        f = add_f32_clamp_components(f, |x, min_val, max_val| x.max(min_val).min(max_val));
+        f = add_f64_clamp_components(f, |x, min_val, max_val| x.max(min_val).min(max_val));
+        f.build()
+    }
+    fn std_mix() -> ConstEvalFunction {
+        let mut f = ConstEvalFunctionBuilder::new();
+        f = add_f32_mix_components(f, |x, y, a| x * (1.0 - a) + y * a);
+        f = add_f64_mix_components(f, |x, y, a| x * (1.0 - a) + y * a);
+        f.build()
+    }
+    fn std_smoothstep() -> ConstEvalFunction {
+        let mut f = ConstEvalFunctionBuilder::new();
+        f = add_f32_smoothstep_components(f, |edge0, edge1, x| {
+            let t = ((x - edge0) / (edge1 - edge0)).clamp(0.0, 1.0);
+            t * t * (3.0 - 2.0 * t)
+        });
+        f = add_f64_smoothstep_components(f, |edge0, edge1, x| {
+            let t = ((x - edge0) / (edge1 - edge0)).clamp(0.0, 1.0);
+            t * t * (3.0 - 2.0 * t)
+        });
+        f.build()
+    }
+    fn std_step() -> ConstEvalFunction {
+        let mut f = ConstEvalFunctionBuilder::new();
+        f = add_f32_binop_components(f, |edge, x| if x < edge { 0.0 } else { 1.0 });
+        f = add_f64_binop_components(f, |edge, x| if x < edge { 0.0 } else { 1.0 });
+        f.build()
+    }
+    fn std_sin() -> ConstEvalFunction {
+        let mut f = ConstEvalFunctionBuilder::new();
+        f = add_f32_unop_components(f, f32::sin);
+        f = add_f64_unop_components(f, f64::sin);
+        f.build()
+    }
+    fn std_cos() -> ConstEvalFunction {
+        let mut f = ConstEvalFunctionBuilder::new();
+        f = add_f32_unop_components(f, f32::cos);
+        f = add_f64_unop_components(f, f64::cos);
+        f.build()
+    }
+    fn std_tan() -> ConstEvalFunction {
+        let mut f = ConstEvalFunctionBuilder::new();
+        f = add_f32_unop_components(f, f32::tan);
+        f = add_f64_unop_components(f, f64::tan);
+        f.build()
+    }
+    fn std_asin() -> ConstEvalFunction {
+        let mut f = ConstEvalFunctionBuilder::new();
+        f = add_f32_unop_components(f, f32::asin);
+        f = add_f64_unop_components(f, f64::asin);
+        f.build()
+    }
+    fn std_acos() -> ConstEvalFunction {
+        let mut f = ConstEvalFunctionBuilder::new();
+        f = add_f32_unop_components(f, f32::acos);
+        f = add_f64_unop_components(f, f64::acos);
+        f.build()
+    }
+    fn std_atan() -> ConstEvalFunction {
+        let mut f = ConstEvalFunctionBuilder::new();
+        f = add_f32_unop_components(f, f32::atan);
+        f = add_f64_unop_components(f, f64::atan);
+        f = add_f32_binop_components(f, f32::atan2);
+        f = add_f64_binop_components(f, f64::atan2);
+        f.build()
+    }
+    fn std_pow() -> ConstEvalFunction {
+        let mut f = ConstEvalFunctionBuilder::new();
+        f = add_f32_binop_components(f, f32::powf);
+        f = add_f64_binop_components(f, f64::powf);
+        f.build()
+    }
+    fn std_fma() -> ConstEvalFunction {
+        let mut f = ConstEvalFunctionBuilder::new();
+        f = add_f32_fma_components(f, f32::mul_add);
+        f = add_f64_fma_components(f, f64::mul_add);
+        f.build()
+    }
+    fn std_exp() -> ConstEvalFunction {
+        let mut f = ConstEvalFunctionBuilder::new();
+        f = add_f32_unop_components(f, f32::exp);
+        f = add_f64_unop_components(f, f64::exp);
+        f.build()
+    }
+    fn std_log() -> ConstEvalFunction {
+        let mut f = ConstEvalFunctionBuilder::new();
+        f = add_f32_unop_components(f, f32::ln);
+        f = add_f64_unop_components(f, f64::ln);
+        f.build()
+    }
+    fn std_exp2() -> ConstEvalFunction {
+        let mut f = ConstEvalFunctionBuilder::new();
+        f = add_f32_unop_components(f, f32::exp2);
+        f = add_f64_unop_components(f, f64::exp2);
+        f.build()
+    }
+    fn std_log2() -> ConstEvalFunction {
+        let mut f = ConstEvalFunctionBuilder::new();
+        f = add_f32_unop_components(f, f32::log2);
+        f = add_f64_unop_components(f, f64::log2);
+        f.build()
+    }
+    fn std_sqrt() -> ConstEvalFunction {
+        let mut f = ConstEvalFunctionBuilder::new();
+        f = add_f32_unop_components(f, f32::sqrt);
+        f = add_f64_unop_components(f, f64::sqrt);
+        f.build()
+    }
+    fn std_inversesqrt() -> ConstEvalFunction {
+        let mut f = ConstEvalFunctionBuilder::new();
+        f = add_f32_unop_components(f, |v: f32| 1.0 / v.sqrt());
+        f = add_f64_unop_components(f, |v: f64| 1.0 / v.sqrt());
+        f.build()
+    }
+    fn std_radians() -> ConstEvalFunction {
+        let mut f = ConstEvalFunctionBuilder::new();
+        f = add_f32_unop_components(f, f32::to_radians);
+        f = add_f64_unop_components(f, f64::to_radians);
+        f.build()
+    }
+    fn std_degrees() -> ConstEvalFunction {
+        let mut f = ConstEvalFunctionBuilder::new();
+        f = add_f32_unop_components(f, f32::to_degrees);
+        f = add_f64_unop_components(f, f64::to_degrees);
+        f.build()
+    }
+    fn std_length() -> ConstEvalFunction {
+        ConstEvalFunctionBuilder::new()
+            .add_overload_1(|v: f32| Some(v.abs()))
+            .add_overload_1(|v: Vector2<f32>| Some(v.norm()))
+            .add_overload_1(|v: Vector3<f32>| Some(v.norm()))
+            .add_overload_1(|v: Vector4<f32>| Some(v.norm()))
+            .add_overload_1(|v: f64| Some(v.abs()))
+            .add_overload_1(|v: Vector2<f64>| Some(v.norm()))
+            .add_overload_1(|v: Vector3<f64>| Some(v.norm()))
+            .add_overload_1(|v: Vector4<f64>| Some(v.norm()))
+            .build()
+    }
+    fn std_distance() -> ConstEvalFunction {
+        ConstEvalFunctionBuilder::new()
+            .add_overload_2(|a: f32, b: f32| Some((a - b).abs()))
+            .add_overload_2(|a: Vector2<f32>, b: Vector2<f32>| Some((a - b).norm()))
+            .add_overload_2(|a: Vector3<f32>, b: Vector3<f32>| Some((a - b).norm()))
+            .add_overload_2(|a: Vector4<f32>, b: Vector4<f32>| Some((a - b).norm()))
+            .add_overload_2(|a: f64, b: f64| Some((a - b).abs()))
+            .add_overload_2(|a: Vector2<f64>, b: Vector2<f64>| Some((a - b).norm()))
+            .add_overload_2(|a: Vector3<f64>, b: Vector3<f64>| Some((a - b).norm()))
+            .add_overload_2(|a: Vector4<f64>, b: Vector4<f64>| Some((a - b).norm()))
+            .build()
+    }
+    fn std_dot() -> ConstEvalFunction {
+        ConstEvalFunctionBuilder::new()
+            .add_overload_2(|a: f32, b: f32| Some(a * b))
+            .add_overload_2(|a: Vector2<f32>, b: Vector2<f32>| Some(a.dot(&b)))
+            .add_overload_2(|a: Vector3<f32>, b: Vector3<f32>| Some(a.dot(&b)))
+            .add_overload_2(|a: Vector4<f32>, b: Vector4<f32>| Some(a.dot(&b)))
+            .add_overload_2(|a: f64, b: f64| Some(a * b))
+            .add_overload_2(|a: Vector2<f64>, b: Vector2<f64>| Some(a.dot(&b)))
+            .add_overload_2(|a: Vector3<f64>, b: Vector3<f64>| Some(a.dot(&b)))
+            .add_overload_2(|a: Vector4<f64>, b: Vector4<f64>| Some(a.dot(&b)))
+            .build()
+    }
+    fn std_cross() -> ConstEvalFunction {
+        ConstEvalFunctionBuilder::new()
+            .add_overload_2(|a: Vector3<f32>, b: Vector3<f32>| Some(a.cross(&b)))
+            .add_overload_2(|a: Vector3<f64>, b: Vector3<f64>| Some(a.cross(&b)))
+            .build()
+    }
+    // `v.normalize()` divides by a zero norm and bakes a `NaN`-filled vector into the constant;
+    // decline the fold instead so a zero-length `normalize()` call is left for runtime
+    // evaluation (where the GLSL result is implementation-defined) rather than silently folding
+    // to `NaN`.
+    fn std_normalize() -> ConstEvalFunction {
+        ConstEvalFunctionBuilder::new()
+            .add_overload_1(|v: Vector2<f32>| { let len = v.norm(); if len == 0.0 { None } else { Some(v / len) } })
+            .add_overload_1(|v: Vector3<f32>| { let len = v.norm(); if len == 0.0 { None } else { Some(v / len) } })
+            .add_overload_1(|v: Vector4<f32>| { let len = v.norm(); if len == 0.0 { None } else { Some(v / len) } })
+            .add_overload_1(|v: Vector2<f64>| { let len = v.norm(); if len == 0.0 { None } else { Some(v / len) } })
+            .add_overload_1(|v: Vector3<f64>| { let len = v.norm(); if len == 0.0 { None } else { Some(v / len) } })
+            .add_overload_1(|v: Vector4<f64>| { let len = v.norm(); if len == 0.0 { None } else { Some(v / len) } })
+            .build()
+    }
+    fn std_transpose() -> ConstEvalFunction {
+        ConstEvalFunctionBuilder::new()
+            .add_overload_1(|v: Matrix2<f32>| Some(v.transpose()))
+            .add_overload_1(|v: Matrix2x3<f32>| Some(v.transpose()))
+            .add_overload_1(|v: Matrix2x4<f32>| Some(v.transpose()))
+            .add_overload_1(|v: Matrix3x2<f32>| Some(v.transpose()))
+            .add_overload_1(|v: Matrix3<f32>| Some(v.transpose()))
+            .add_overload_1(|v: Matrix3x4<f32>| Some(v.transpose()))
+            .add_overload_1(|v: Matrix4x2<f32>| Some(v.transpose()))
+            .add_overload_1(|v: Matrix4x3<f32>| Some(v.transpose()))
+            .add_overload_1(|v: Matrix4<f32>| Some(v.transpose()))
+            .add_overload_1(|v: Matrix2<f64>| Some(v.transpose()))
+            .add_overload_1(|v: Matrix2x3<f64>| Some(v.transpose()))
+            .add_overload_1(|v: Matrix2x4<f64>| Some(v.transpose()))
+            .add_overload_1(|v: Matrix3x2<f64>| Some(v.transpose()))
+            .add_overload_1(|v: Matrix3<f64>| Some(v.transpose()))
+            .add_overload_1(|v: Matrix3x4<f64>| Some(v.transpose()))
+            .add_overload_1(|v: Matrix4x2<f64>| Some(v.transpose()))
+            .add_overload_1(|v: Matrix4x3<f64>| Some(v.transpose()))
+            .add_overload_1(|v: Matrix4<f64>| Some(v.transpose()))
+            .build()
+    }
+    fn std_matrix_comp_mult() -> ConstEvalFunction {
+        ConstEvalFunctionBuilder::new()
+            .add_overload_2(|a: Matrix2<f32>, b: Matrix2<f32>| Some(a.zip_map(&b, |x, y| x * y)))
+            .add_overload_2(|a: Matrix2x3<f32>, b: Matrix2x3<f32>| Some(a.zip_map(&b, |x, y| x * y)))
+            .add_overload_2(|a: Matrix2x4<f32>, b: Matrix2x4<f32>| Some(a.zip_map(&b, |x, y| x * y)))
+            .add_overload_2(|a: Matrix3x2<f32>, b: Matrix3x2<f32>| Some(a.zip_map(&b, |x, y| x * y)))
+            .add_overload_2(|a: Matrix3<f32>, b: Matrix3<f32>| Some(a.zip_map(&b, |x, y| x * y)))
+            .add_overload_2(|a: Matrix3x4<f32>, b: Matrix3x4<f32>| Some(a.zip_map(&b, |x, y| x * y)))
+            .add_overload_2(|a: Matrix4x2<f32>, b: Matrix4x2<f32>| Some(a.zip_map(&b, |x, y| x * y)))
+            .add_overload_2(|a: Matrix4x3<f32>, b: Matrix4x3<f32>| Some(a.zip_map(&b, |x, y| x * y)))
+            .add_overload_2(|a: Matrix4<f32>, b: Matrix4<f32>| Some(a.zip_map(&b, |x, y| x * y)))
+            .add_overload_2(|a: Matrix2<f64>, b: Matrix2<f64>| Some(a.zip_map(&b, |x, y| x * y)))
+            .add_overload_2(|a: Matrix2x3<f64>, b: Matrix2x3<f64>| Some(a.zip_map(&b, |x, y| x * y)))
+            .add_overload_2(|a: Matrix2x4<f64>, b: Matrix2x4<f64>| Some(a.zip_map(&b, |x, y| x * y)))
+            .add_overload_2(|a: Matrix3x2<f64>, b: Matrix3x2<f64>| Some(a.zip_map(&b, |x, y| x * y)))
+            .add_overload_2(|a: Matrix3<f64>, b: Matrix3<f64>| Some(a.zip_map(&b, |x, y| x * y)))
+            .add_overload_2(|a: Matrix3x4<f64>, b: Matrix3x4<f64>| Some(a.zip_map(&b, |x, y| x * y)))
+            .add_overload_2(|a: Matrix4x2<f64>, b: Matrix4x2<f64>| Some(a.zip_map(&b, |x, y| x * y)))
+            .add_overload_2(|a: Matrix4x3<f64>, b: Matrix4x3<f64>| Some(a.zip_map(&b, |x, y| x * y)))
+            .add_overload_2(|a: Matrix4<f64>, b: Matrix4<f64>| Some(a.zip_map(&b, |x, y| x * y)))
+            .build()
+    }
+    fn std_outer_product() -> ConstEvalFunction {
+        ConstEvalFunctionBuilder::new()
+            .add_overload_2(|c: Vector2<f32>, r: Vector2<f32>| Some(c * r.transpose()))
+            .add_overload_2(|c: Vector2<f32>, r: Vector3<f32>| Some(c * r.transpose()))
+            .add_overload_2(|c: Vector2<f32>, r: Vector4<f32>| Some(c * r.transpose()))
+            .add_overload_2(|c: Vector3<f32>, r: Vector2<f32>| Some(c * r.transpose()))
+            .add_overload_2(|c: Vector3<f32>, r: Vector3<f32>| Some(c * r.transpose()))
+            .add_overload_2(|c: Vector3<f32>, r: Vector4<f32>| Some(c * r.transpose()))
+            .add_overload_2(|c: Vector4<f32>, r: Vector2<f32>| Some(c * r.transpose()))
+            .add_overload_2(|c: Vector4<f32>, r: Vector3<f32>| Some(c * r.transpose()))
+            .add_overload_2(|c: Vector4<f32>, r: Vector4<f32>| Some(c * r.transpose()))
+            .add_overload_2(|c: Vector2<f64>, r: Vector2<f64>| Some(c * r.transpose()))
+            .add_overload_2(|c: Vector2<f64>, r: Vector3<f64>| Some(c * r.transpose()))
+            .add_overload_2(|c: Vector2<f64>, r: Vector4<f64>| Some(c * r.transpose()))
+            .add_overload_2(|c: Vector3<f64>, r: Vector2<f64>| Some(c * r.transpose()))
+            .add_overload_2(|c: Vector3<f64>, r: Vector3<f64>| Some(c * r.transpose()))
+            .add_overload_2(|c: Vector3<f64>, r: Vector4<f64>| Some(c * r.transpose()))
+            .add_overload_2(|c: Vector4<f64>, r: Vector2<f64>| Some(c * r.transpose()))
+            .add_overload_2(|c: Vector4<f64>, r: Vector3<f64>| Some(c * r.transpose()))
+            .add_overload_2(|c: Vector4<f64>, r: Vector4<f64>| Some(c * r.transpose()))
+            .build()
+    }
+    fn std_determinant() -> ConstEvalFunction {
+        ConstEvalFunctionBuilder::new()
+            .add_overload_1(|v: Matrix2<f32>| Some(v.determinant()))
+            .add_overload_1(|v: Matrix3<f32>| Some(v.determinant()))
+            .add_overload_1(|v: Matrix4<f32>| Some(v.determinant()))
+            .add_overload_1(|v: Matrix2<f64>| Some(v.determinant()))
+            .add_overload_1(|v: Matrix3<f64>| Some(v.determinant()))
+            .add_overload_1(|v: Matrix4<f64>| Some(v.determinant()))
+            .build()
+    }
+    // Declines (like `std_normalize` declines on a zero-length vector) rather than baking in
+    // an infinite/NaN result when the matrix is exactly singular.
+    fn std_inverse() -> ConstEvalFunction {
+        ConstEvalFunctionBuilder::new()
+            .add_overload_1(|v: Matrix2<f32>| if v.determinant() == 0.0 { None } else { v.try_inverse() })
+            .add_overload_1(|v: Matrix3<f32>| if v.determinant() == 0.0 { None } else { v.try_inverse() })
+            .add_overload_1(|v: Matrix4<f32>| if v.determinant() == 0.0 { None } else { v.try_inverse() })
+            .add_overload_1(|v: Matrix2<f64>| if v.determinant() == 0.0 { None } else { v.try_inverse() })
+            .add_overload_1(|v: Matrix3<f64>| if v.determinant() == 0.0 { None } else { v.try_inverse() })
+            .add_overload_1(|v: Matrix4<f64>| if v.determinant() == 0.0 { None } else { v.try_inverse() })
+            .build()
+    }
+
+    /// Registers the GLSL standard library functions permitted in constant expressions.
+    pub fn register_std_builtin_const_functions<F: FnMut(Identifier, ConstEvalFunction)>(mut f: F) {
+        f(Identifier::new("abs").unwrap(), std_abs());
+        f(Identifier::new("sign").unwrap(), std_sign());
+        f(Identifier::new("floor").unwrap(), std_floor());
+        f(Identifier::new("ceil").unwrap(), std_ceil());
+        f(Identifier::new("round").unwrap(), std_round());
+        f(Identifier::new("trunc").unwrap(), std_trunc());
+        f(Identifier::new("fract").unwrap(), std_fract());
+        f(Identifier::new("mod").unwrap(), std_mod());
+        f(Identifier::new("min").unwrap(), std_min());
+        f(Identifier::new("max").unwrap(), std_max());
+        f(Identifier::new("clamp").unwrap(), std_clamp());
+        f(Identifier::new("mix").unwrap(), std_mix());
+        f(Identifier::new("smoothstep").unwrap(), std_smoothstep());
+        f(Identifier::new("step").unwrap(), std_step());
+        f(Identifier::new("sin").unwrap(), std_sin());
+        f(Identifier::new("cos").unwrap(), std_cos());
+        f(Identifier::new("tan").unwrap(), std_tan());
+        f(Identifier::new("asin").unwrap(), std_asin());
+        f(Identifier::new("acos").unwrap(), std_acos());
+        f(Identifier::new("atan").unwrap(), std_atan());
+        f(Identifier::new("pow").unwrap(), std_pow());
+        f(Identifier::new("fma").unwrap(), std_fma());
+        f(Identifier::new("exp").unwrap(), std_exp());
+        f(Identifier::new("log").unwrap(), std_log());
+        f(Identifier::new("exp2").unwrap(), std_exp2());
+        f(Identifier::new("log2").unwrap(), std_log2());
+        f(Identifier::new("sqrt").unwrap(), std_sqrt());
+        f(Identifier::new("inversesqrt").unwrap(), std_inversesqrt());
+        f(Identifier::new("radians").unwrap(), std_radians());
+        f(Identifier::new("degrees").unwrap(), std_degrees());
+        f(Identifier::new("length").unwrap(), std_length());
+        f(Identifier::new("distance").unwrap(), std_distance());
+        f(Identifier::new("dot").unwrap(), std_dot());
+        f(Identifier::new("cross").unwrap(), std_cross());
+        f(Identifier::new("normalize").unwrap(), std_normalize());
+        f(Identifier::new("transpose").unwrap(), std_transpose());
+        f(Identifier::new("matrixCompMult").unwrap(), std_matrix_comp_mult());
+        f(Identifier::new("outerProduct").unwrap(), std_outer_product());
+        f(Identifier::new("determinant").unwrap(), std_determinant());
+        f(Identifier::new("inverse").unwrap(), std_inverse());
+    }
+
+    fn add_scalar_constructor<T>(f: ConstEvalFunctionBuilder) -> ConstEvalFunctionBuilder where T: Scalar + ConstParameter + ScalarConstructFrom<bool> + ScalarConstructFrom<i32> + ScalarConstructFrom<u32> + ScalarConstructFrom<f32> + ScalarConstructFrom<f64> + ScalarConstructFrom<f16> {
         f.add_overload_1(|v: ConstSVVal<bool>| Some(T::construct_from(v.column_iter().next().unwrap())))
             .add_overload_1(|v: ConstSVVal<i32>| Some(T::construct_from(v.column_iter().next().unwrap())))
             .add_overload_1(|v: ConstSVVal<u32>| Some(T::construct_from(v.column_iter().next().unwrap())))
             .add_overload_1(|v: ConstSVVal<f32>| Some(T::construct_from(v.column_iter().next().unwrap())))
             .add_overload_1(|v: ConstSVVal<f64>| Some(T::construct_from(v.column_iter().next().unwrap())))
+            .add_overload_1(|v: ConstSVVal<f16>| Some(T::construct_from(v.column_iter().next().unwrap())))
     }
 
-    enum ScalarIterWrapper<'a, T: ScalarConstructFrom<bool> + ScalarConstructFrom<i32> + ScalarConstructFrom<u32> + ScalarConstructFrom<f32> + ScalarConstructFrom<f64>> {
+    enum ScalarIterWrapper<'a, T: ScalarConstructFrom<bool> + ScalarConstructFrom<i32> + ScalarConstructFrom<u32> + ScalarConstructFrom<f32> + ScalarConstructFrom<f64> + ScalarConstructFrom<f16>> {
         Bool(std::slice::Iter<'a, bool>, PhantomData<T>),
         Int(std::slice::Iter<'a, i32>),
         UInt(std::slice::Iter<'a, u32>),
         Float(std::slice::Iter<'a, f32>),
         Double(std::slice::Iter<'a, f64>),
+        Half(std::slice::Iter<'a, f16>),
     }
 
-    impl<'a, T> ScalarIterWrapper<'a, T> where T: ScalarConstructFrom<bool> + ScalarConstructFrom<i32> + ScalarConstructFrom<u32> + ScalarConstructFrom<f32> + ScalarConstructFrom<f64> {
+    impl<'a, T> ScalarIterWrapper<'a, T> where T: ScalarConstructFrom<bool> + ScalarConstructFrom<i32> + ScalarConstructFrom<u32> + ScalarConstructFrom<f32> + ScalarConstructFrom<f64> + ScalarConstructFrom<f16> {
         fn from_base_val(val: &'a ConstBaseVal) -> Self {
             match val {
                 ConstBaseVal::Bool(v) => Self::Bool(v.column_iter(), PhantomData),
@@ -2130,11 +3629,12 @@ mod function {
                 ConstBaseVal::UInt(v) => Self::UInt(v.column_iter()),
                 ConstBaseVal::Float(v) => Self::Float(v.column_iter()),
                 ConstBaseVal::Double(v) => Self::Double(v.column_iter()),
+                ConstBaseVal::Float16(v) => Self::Half(v.column_iter()),
             }
         }
     }
 
-    impl<'a, T> Iterator for ScalarIterWrapper<'a, T> where T: ScalarConstructFrom<bool> + ScalarConstructFrom<i32> + ScalarConstructFrom<u32> + ScalarConstructFrom<f32> + ScalarConstructFrom<f64> {
+    impl<'a, T> Iterator for ScalarIterWrapper<'a, T> where T: ScalarConstructFrom<bool> + ScalarConstructFrom<i32> + ScalarConstructFrom<u32> + ScalarConstructFrom<f32> + ScalarConstructFrom<f64> + ScalarConstructFrom<f16> {
         type Item = T;
 
         fn next(&mut self) -> Option<Self::Item> {
@@ -2144,17 +3644,18 @@ mod function {
                 ScalarIterWrapper::UInt(i) => i.next().map(T::construct_from),
                 ScalarIterWrapper::Float(i) => i.next().map(T::construct_from),
                 ScalarIterWrapper::Double(i) => i.next().map(T::construct_from),
+                ScalarIterWrapper::Half(i) => i.next().map(T::construct_from),
             }
         }
     }
 
-    struct ValIterator<'a, 'b, T: ScalarConstructFrom<bool> + ScalarConstructFrom<i32> + ScalarConstructFrom<u32> + ScalarConstructFrom<f32> + ScalarConstructFrom<f64>> {
+    struct ValIterator<'a, 'b, T: ScalarConstructFrom<bool> + ScalarConstructFrom<i32> + ScalarConstructFrom<u32> + ScalarConstructFrom<f32> + ScalarConstructFrom<f64> + ScalarConstructFrom<f16>> {
         params: &'a[&'b ConstBaseVal],
         current_param: usize,
         current_iter: Option<ScalarIterWrapper<'b, T>>,
     }
 
-    impl<'a, 'b, T> ValIterator<'a, 'b, T> where T: ScalarConstructFrom<bool> + ScalarConstructFrom<i32> + ScalarConstructFrom<u32> + ScalarConstructFrom<f32> + ScalarConstructFrom<f64> {
+    impl<'a, 'b, T> ValIterator<'a, 'b, T> where T: ScalarConstructFrom<bool> + ScalarConstructFrom<i32> + ScalarConstructFrom<u32> + ScalarConstructFrom<f32> + ScalarConstructFrom<f64> + ScalarConstructFrom<f16> {
         fn new(params: &'a[&'b ConstBaseVal]) -> Self {
             let current_iter = if params.len() != 0 {
                 Some(ScalarIterWrapper::from_base_val(params[0]))
@@ -2170,7 +3671,7 @@ mod function {
         }
     }
 
-    impl<'a, 'b, T> Iterator for ValIterator<'a, 'b, T> where T: ScalarConstructFrom<bool> + ScalarConstructFrom<i32> + ScalarConstructFrom<u32> + ScalarConstructFrom<f32> + ScalarConstructFrom<f64> {
+    impl<'a, 'b, T> Iterator for ValIterator<'a, 'b, T> where T: ScalarConstructFrom<bool> + ScalarConstructFrom<i32> + ScalarConstructFrom<u32> + ScalarConstructFrom<f32> + ScalarConstructFrom<f64> + ScalarConstructFrom<f16> {
         type Item = T;
 
         fn next(&mut self) -> Option<Self::Item> {
@@ -2192,7 +3693,7 @@ mod function {
     }
 
     type AVector<const R: usize, T> = Matrix<T, Const<R>, U1, ArrayStorage<T, R, 1>>;
-    fn add_vec_constructor<const R: usize, T>(f: ConstEvalFunctionBuilder) -> ConstEvalFunctionBuilder where T: Scalar + ScalarConstructFrom<bool> + ScalarConstructFrom<i32> + ScalarConstructFrom<u32> + ScalarConstructFrom<f32> + ScalarConstructFrom<f64>, AVector<R, T>: ConstParameter {
+    fn add_vec_constructor<const R: usize, T>(f: ConstEvalFunctionBuilder) -> ConstEvalFunctionBuilder where T: Scalar + ScalarConstructFrom<bool> + ScalarConstructFrom<i32> + ScalarConstructFrom<u32> + ScalarConstructFrom<f32> + ScalarConstructFrom<f64> + ScalarConstructFrom<f16>, AVector<R, T>: ConstParameter {
         f.add_generic(|params| {
             if params.len() == 0 {
                 return None;
@@ -2220,7 +3721,7 @@ mod function {
     }
 
     type AMatrix<const R: usize, const C: usize, T> = Matrix<T, Const<R>, Const<C>, ArrayStorage<T, R, C>>;
-    fn add_mat_constructor<const R: usize, const C: usize, T>(f: ConstEvalFunctionBuilder) -> ConstEvalFunctionBuilder where T: Scalar + Zero + One + ScalarConstructFrom<bool> + ScalarConstructFrom<i32> + ScalarConstructFrom<u32> + ScalarConstructFrom<f32> + ScalarConstructFrom<f64>, AMatrix<R, C, T>: ConstParameter {
+    fn add_mat_constructor<const R: usize, const C: usize, T>(f: ConstEvalFunctionBuilder) -> ConstEvalFunctionBuilder where T: Scalar + Zero + One + ScalarConstructFrom<bool> + ScalarConstructFrom<i32> + ScalarConstructFrom<u32> + ScalarConstructFrom<f32> + ScalarConstructFrom<f64> + ScalarConstructFrom<f16>, AMatrix<R, C, T>: ConstParameter {
         f.add_generic(|params| {
             if params.len() == 0 {
                 return None;
@@ -2229,9 +3730,16 @@ mod function {
                 if params[0].get_shape() == BaseTypeShape::Scalar {
                     return Some(AMatrix::<R, C, T>::from_diagonal_element(ValIterator::new(params).next().unwrap()).into_const_base_val());
                 } else {
+                    // Matrices are only ever backed by the three SVM base types (`Float`,
+                    // `Double`, `Float16`); `Bool`/`Int`/`UInt` have no matrix shape, so there's
+                    // nothing else to match here. Unlike the implicit-promotion lattice in
+                    // `ParameterBaseType::cast_cmp`, a constructor call is always an *explicit*
+                    // GLSL conversion, so narrowing (e.g. `mat3(dmat3(...))`) is allowed the same
+                    // as widening.
                     let converted = match params[0] {
                         ConstBaseVal::Float(ConstSVMVal::Matrix(v)) => Some(v.map(|v| T::construct_from(&v))),
                         ConstBaseVal::Double(ConstSVMVal::Matrix(v)) => Some(v.map(|v| T::construct_from(&v))),
+                        ConstBaseVal::Float16(ConstSVMVal::Matrix(v)) => Some(v.map(|v| T::construct_from(&v))),
                         _ => None,
                     };
                     if let Some(converted) = converted {
@@ -2291,19 +3799,62 @@ mod function {
         f(Identifier::new("mat42").unwrap(), add_mat_constructor::<4, 2, f32>(ConstEvalFunctionBuilder::new()).build());
         f(Identifier::new("mat43").unwrap(), add_mat_constructor::<4, 3, f32>(ConstEvalFunctionBuilder::new()).build());
         f(Identifier::new("mat4").unwrap(), add_mat_constructor::<4, 4, f32>(ConstEvalFunctionBuilder::new()).build());
-        f(Identifier::new("dmat2").unwrap(), add_mat_constructor::<2, 2, f32>(ConstEvalFunctionBuilder::new()).build());
-        f(Identifier::new("dmat23").unwrap(), add_mat_constructor::<2, 3, f32>(ConstEvalFunctionBuilder::new()).build());
-        f(Identifier::new("dmat24").unwrap(), add_mat_constructor::<2, 4, f32>(ConstEvalFunctionBuilder::new()).build());
-        f(Identifier::new("dmat32").unwrap(), add_mat_constructor::<3, 2, f32>(ConstEvalFunctionBuilder::new()).build());
-        f(Identifier::new("dmat3").unwrap(), add_mat_constructor::<3, 3, f32>(ConstEvalFunctionBuilder::new()).build());
-        f(Identifier::new("dmat34").unwrap(), add_mat_constructor::<3, 4, f32>(ConstEvalFunctionBuilder::new()).build());
-        f(Identifier::new("dmat42").unwrap(), add_mat_constructor::<4, 2, f32>(ConstEvalFunctionBuilder::new()).build());
-        f(Identifier::new("dmat43").unwrap(), add_mat_constructor::<4, 3, f32>(ConstEvalFunctionBuilder::new()).build());
-        f(Identifier::new("dmat4").unwrap(), add_mat_constructor::<4, 4, f32>(ConstEvalFunctionBuilder::new()).build());
-    }
-
-    pub fn register_builtin_const_functions<F: FnMut(Identifier, ConstEvalFunction)>(f: F) {
-        register_constructor_const_functions(f);
+        f(Identifier::new("dmat2").unwrap(), add_mat_constructor::<2, 2, f64>(ConstEvalFunctionBuilder::new()).build());
+        f(Identifier::new("dmat23").unwrap(), add_mat_constructor::<2, 3, f64>(ConstEvalFunctionBuilder::new()).build());
+        f(Identifier::new("dmat24").unwrap(), add_mat_constructor::<2, 4, f64>(ConstEvalFunctionBuilder::new()).build());
+        f(Identifier::new("dmat32").unwrap(), add_mat_constructor::<3, 2, f64>(ConstEvalFunctionBuilder::new()).build());
+        f(Identifier::new("dmat3").unwrap(), add_mat_constructor::<3, 3, f64>(ConstEvalFunctionBuilder::new()).build());
+        f(Identifier::new("dmat34").unwrap(), add_mat_constructor::<3, 4, f64>(ConstEvalFunctionBuilder::new()).build());
+        f(Identifier::new("dmat42").unwrap(), add_mat_constructor::<4, 2, f64>(ConstEvalFunctionBuilder::new()).build());
+        f(Identifier::new("dmat43").unwrap(), add_mat_constructor::<4, 3, f64>(ConstEvalFunctionBuilder::new()).build());
+        f(Identifier::new("dmat4").unwrap(), add_mat_constructor::<4, 4, f64>(ConstEvalFunctionBuilder::new()).build());
+        f(Identifier::new("float16_t").unwrap(), add_scalar_constructor::<f16>(ConstEvalFunctionBuilder::new()).build());
+        f(Identifier::new("f16vec2").unwrap(), add_vec_constructor::<2, f16>(ConstEvalFunctionBuilder::new()).build());
+        f(Identifier::new("f16vec3").unwrap(), add_vec_constructor::<3, f16>(ConstEvalFunctionBuilder::new()).build());
+        f(Identifier::new("f16vec4").unwrap(), add_vec_constructor::<4, f16>(ConstEvalFunctionBuilder::new()).build());
+        f(Identifier::new("f16mat2").unwrap(), add_mat_constructor::<2, 2, f16>(ConstEvalFunctionBuilder::new()).build());
+        f(Identifier::new("f16mat23").unwrap(), add_mat_constructor::<2, 3, f16>(ConstEvalFunctionBuilder::new()).build());
+        f(Identifier::new("f16mat24").unwrap(), add_mat_constructor::<2, 4, f16>(ConstEvalFunctionBuilder::new()).build());
+        f(Identifier::new("f16mat32").unwrap(), add_mat_constructor::<3, 2, f16>(ConstEvalFunctionBuilder::new()).build());
+        f(Identifier::new("f16mat3").unwrap(), add_mat_constructor::<3, 3, f16>(ConstEvalFunctionBuilder::new()).build());
+        f(Identifier::new("f16mat34").unwrap(), add_mat_constructor::<3, 4, f16>(ConstEvalFunctionBuilder::new()).build());
+        f(Identifier::new("f16mat42").unwrap(), add_mat_constructor::<4, 2, f16>(ConstEvalFunctionBuilder::new()).build());
+        f(Identifier::new("f16mat43").unwrap(), add_mat_constructor::<4, 3, f16>(ConstEvalFunctionBuilder::new()).build());
+        f(Identifier::new("f16mat4").unwrap(), add_mat_constructor::<4, 4, f16>(ConstEvalFunctionBuilder::new()).build());
+    }
+
+    /// The fixed set of GLSL type-constructor names, used to tell a failed constructor call
+    /// (wrong argument shapes/count) apart from a failed ordinary function call.
+    const CONSTRUCTOR_NAMES: &[&str] = &[
+        "bool", "int", "uint", "float", "double",
+        "bvec2", "bvec3", "bvec4",
+        "ivec2", "ivec3", "ivec4",
+        "uvec2", "uvec3", "uvec4",
+        "vec2", "vec3", "vec4",
+        "dvec2", "dvec3", "dvec4",
+        "mat2", "mat23", "mat24", "mat32", "mat3", "mat34", "mat42", "mat43", "mat4",
+        "dmat2", "dmat23", "dmat24", "dmat32", "dmat3", "dmat34", "dmat42", "dmat43", "dmat4",
+        "int8_t", "int16_t", "int64_t", "uint8_t", "uint16_t", "uint64_t", "float16_t",
+        "i8vec2", "i8vec3", "i8vec4",
+        "i16vec2", "i16vec3", "i16vec4",
+        "i64vec2", "i64vec3", "i64vec4",
+        "u8vec2", "u8vec3", "u8vec4",
+        "u16vec2", "u16vec3", "u16vec4",
+        "u64vec2", "u64vec3", "u64vec4",
+        "f16vec2", "f16vec3", "f16vec4",
+        "f16mat2", "f16mat23", "f16mat24", "f16mat32", "f16mat3", "f16mat34", "f16mat42", "f16mat43", "f16mat4",
+    ];
+
+    /// Whether `name` names one of GLSL's built-in type constructors (as opposed to a regular
+    /// function), used to pick between [`ConstEvalError::IllegalConstructor`] and
+    /// [`ConstEvalError::NoMatchingFunctionOverload`] when a call fails to fold.
+    pub(crate) fn is_constructor_name(name: &str) -> bool {
+        CONSTRUCTOR_NAMES.contains(&name)
+    }
+
+    pub fn register_builtin_const_functions<F: FnMut(Identifier, ConstEvalFunction)>(mut f: F) {
+        register_constructor_const_functions(&mut f);
+        register_std_builtin_const_functions(&mut f);
     }
 
     lazy_static! {
@@ -2318,7 +3869,7 @@ mod function {
     mod tests {
         use super::*;
 
-        const BASE_TYPE_VALUES: &[ParameterBaseType] = &[ParameterBaseType::Bool, ParameterBaseType::Int, ParameterBaseType::UInt, ParameterBaseType::Float, ParameterBaseType::Double];
+        const BASE_TYPE_VALUES: &[ParameterBaseType] = &[ParameterBaseType::Bool, ParameterBaseType::Int, ParameterBaseType::UInt, ParameterBaseType::Half, ParameterBaseType::Float, ParameterBaseType::Double];
         const SHAPE_VALUES: &[ParameterShape] = &[ParameterShape::Scalar, ParameterShape::Vec2, ParameterShape::Vec3, ParameterShape::Vec4, ParameterShape::Mat2, ParameterShape::Mat23, ParameterShape::Mat24, ParameterShape::Mat32, ParameterShape::Mat3, ParameterShape::Mat34, ParameterShape::Mat42, ParameterShape::Mat43, ParameterShape::Mat4, ParameterShape::GenericM, ParameterShape::GenericSV, ParameterShape::GenericSVM];
 
         #[test]
@@ -2327,6 +3878,10 @@ mod function {
             assert_eq!(ParameterBaseType::Bool.cast_cmp(&ParameterBaseType::Float), None);
             assert_eq!(ParameterBaseType::Int.cast_cmp(&ParameterBaseType::UInt), Some(Ordering::Less));
             assert_eq!(ParameterBaseType::Double.cast_cmp(&ParameterBaseType::UInt), Some(Ordering::Greater));
+            assert_eq!(ParameterBaseType::UInt.cast_cmp(&ParameterBaseType::Half), Some(Ordering::Less));
+            assert_eq!(ParameterBaseType::Half.cast_cmp(&ParameterBaseType::Double), Some(Ordering::Less));
+            assert_eq!(ParameterBaseType::Float.cast_cmp(&ParameterBaseType::Half), Some(Ordering::Greater));
+            assert_eq!(ParameterBaseType::Half.cast_cmp(&ParameterBaseType::Bool), None);
         }
 
         #[test]
@@ -2368,6 +3923,8 @@ mod function {
             assert(T::new(B::Int, S::Scalar), T::new(B::UInt, S::Scalar));
             assert(T::new(B::Int, S::Scalar), T::new(B::Float, S::Scalar));
             assert(T::new(B::Int, S::Scalar), T::new(B::Double, S::Scalar));
+            assert(T::new(B::UInt, S::Scalar), T::new(B::Half, S::Scalar));
+            assert(T::new(B::Half, S::Scalar), T::new(B::Float, S::Scalar));
 
             assert(T::new(B::Bool, S::Mat3), T::new(B::Bool, S::GenericSVM));
             assert(T::new(B::UInt, S::Mat3), T::new(B::UInt, S::GenericSVM));
@@ -2391,37 +3948,195 @@ mod function {
             assert!(!func.compatible_with(&[(S::Mat4, T::Float), (S::Mat4, T::Float)]));
         }
 
+        #[test]
+        fn const_eval_function_instance_eval_reports_errors_instead_of_panicking() {
+            let func = ConstEvalFunctionInstance::from_fn_1(|v: i32| Some(v));
+            let a = ConstBaseVal::from(3i32);
+            let b = ConstBaseVal::from(4i32);
+            assert_eq!(func.eval(&[&a, &b]), Err(ConstEvalError::ParameterCountMismatch { expected: 1, got: 2 }));
+
+            let bool_val = ConstBaseVal::from(true);
+            assert_eq!(func.eval(&[&bool_val]), Err(ConstEvalError::ImplicitCastFailed {
+                from: ParameterType::new(ParameterBaseType::Bool, ParameterShape::Scalar),
+                to: ParameterType::new(ParameterBaseType::Int, ParameterShape::Scalar),
+            }));
+        }
+
         #[test]
         fn op_unary_add() {
             let v = ConstBaseVal::from(true);
-            assert_eq!(OP_UNARY_ADD.eval(&[&v]), None);
+            assert_eq!(OP_UNARY_ADD.eval(&[&v]), Ok(None));
             let v = ConstBaseVal::from(-3i32);
-            assert_eq!(OP_UNARY_ADD.eval(&[&v]), Some(v));
+            assert_eq!(OP_UNARY_ADD.eval(&[&v]), Ok(Some(v)));
             let v = ConstBaseVal::from(Vector2::from_element(4u32));
-            assert_eq!(OP_UNARY_ADD.eval(&[&v]), Some(v));
+            assert_eq!(OP_UNARY_ADD.eval(&[&v]), Ok(Some(v)));
             let v = ConstBaseVal::from(Matrix4::from_diagonal_element(-34f32));
-            assert_eq!(OP_UNARY_ADD.eval(&[&v]), Some(v));
+            assert_eq!(OP_UNARY_ADD.eval(&[&v]), Ok(Some(v)));
             let v = ConstBaseVal::from(Vector4::new(-9f64, 0f64, 3.78342979823f64, 1f64));
-            assert_eq!(OP_UNARY_ADD.eval(&[&v]), Some(v));
+            assert_eq!(OP_UNARY_ADD.eval(&[&v]), Ok(Some(v)));
         }
 
         #[test]
         fn op_unary_minus() {
-            assert_eq!(OP_UNARY_MINUS.eval(&[&ConstBaseVal::from(true)]), None);
-            assert_eq!(OP_UNARY_MINUS.eval(&[&ConstBaseVal::from(-3i32)]), Some(ConstBaseVal::from(3i32)));
-            assert_eq!(OP_UNARY_MINUS.eval(&[&ConstBaseVal::from(Vector2::from_element(4u32))]), Some(ConstBaseVal::from(Vector2::from_element(4294967292u32))));
-            assert_eq!(OP_UNARY_MINUS.eval(&[&ConstBaseVal::from(Matrix4::from_diagonal_element(-34f32))]), Some(ConstBaseVal::from(Matrix4::from_diagonal_element(34f32))));
-            assert_eq!(OP_UNARY_MINUS.eval(&[&ConstBaseVal::from(Vector4::new(-9f64, 0f64, 3.78342979823f64, 1f64))]), Some(ConstBaseVal::from(Vector4::new(9f64, 0f64, -3.78342979823f64, -1f64))));
+            assert_eq!(OP_UNARY_MINUS.eval(&[&ConstBaseVal::from(true)]), Ok(None));
+            assert_eq!(OP_UNARY_MINUS.eval(&[&ConstBaseVal::from(-3i32)]), Ok(Some(ConstBaseVal::from(3i32))));
+            assert_eq!(OP_UNARY_MINUS.eval(&[&ConstBaseVal::from(Vector2::from_element(4u32))]), Ok(Some(ConstBaseVal::from(Vector2::from_element(4294967292u32)))));
+            assert_eq!(OP_UNARY_MINUS.eval(&[&ConstBaseVal::from(Matrix4::from_diagonal_element(-34f32))]), Ok(Some(ConstBaseVal::from(Matrix4::from_diagonal_element(34f32)))));
+            assert_eq!(OP_UNARY_MINUS.eval(&[&ConstBaseVal::from(Vector4::new(-9f64, 0f64, 3.78342979823f64, 1f64))]), Ok(Some(ConstBaseVal::from(Vector4::new(9f64, 0f64, -3.78342979823f64, -1f64)))));
         }
 
         #[test]
         fn op_unary_not() {
-            assert_eq!(OP_UNARY_NOT.eval(&[&ConstBaseVal::from(true)]), Some(ConstBaseVal::from(false)));
-            assert_eq!(OP_UNARY_NOT.eval(&[&ConstBaseVal::from(Vector3::from_element(false))]), None);
-            assert_eq!(OP_UNARY_NOT.eval(&[&ConstBaseVal::from(-3i32)]), None);
-            assert_eq!(OP_UNARY_NOT.eval(&[&ConstBaseVal::from(Vector2::from_element(4u32))]), None);
-            assert_eq!(OP_UNARY_NOT.eval(&[&ConstBaseVal::from(Matrix4::from_diagonal_element(-34f32))]), None);
-            assert_eq!(OP_UNARY_NOT.eval(&[&ConstBaseVal::from(Vector4::new(-9f64, 0f64, 3.78342979823f64, 1f64))]), None);
+            assert_eq!(OP_UNARY_NOT.eval(&[&ConstBaseVal::from(true)]), Ok(Some(ConstBaseVal::from(false))));
+            assert_eq!(OP_UNARY_NOT.eval(&[&ConstBaseVal::from(Vector3::from_element(false))]), Ok(None));
+            assert_eq!(OP_UNARY_NOT.eval(&[&ConstBaseVal::from(-3i32)]), Ok(None));
+            assert_eq!(OP_UNARY_NOT.eval(&[&ConstBaseVal::from(Vector2::from_element(4u32))]), Ok(None));
+            assert_eq!(OP_UNARY_NOT.eval(&[&ConstBaseVal::from(Matrix4::from_diagonal_element(-34f32))]), Ok(None));
+            assert_eq!(OP_UNARY_NOT.eval(&[&ConstBaseVal::from(Vector4::new(-9f64, 0f64, 3.78342979823f64, 1f64))]), Ok(None));
+        }
+
+        #[test]
+        fn op_binary_add_sub_mul_div_mod_fold() {
+            assert_eq!(OP_BINARY_ADD.eval(&[&ConstBaseVal::from(true), &ConstBaseVal::from(true)]), Ok(None));
+            assert_eq!(OP_BINARY_ADD.eval(&[&ConstBaseVal::from(1i32), &ConstBaseVal::from(2.5f32)]), Ok(Some(ConstBaseVal::from(3.5f32))));
+            assert_eq!(OP_BINARY_SUB.eval(&[&ConstBaseVal::from(Vector3::new(4.0f32, 5.0f32, 6.0f32)), &ConstBaseVal::from(Vector3::new(1.0f32, 2.0f32, 3.0f32))]), Ok(Some(ConstBaseVal::from(Vector3::new(3.0f32, 3.0f32, 3.0f32)))));
+            assert_eq!(OP_BINARY_MULT.eval(&[&ConstBaseVal::from(Vector3::from_element(1.0f32)), &ConstBaseVal::from(2.0f32)]), Ok(Some(ConstBaseVal::from(Vector3::from_element(2.0f32)))));
+            assert_eq!(OP_BINARY_DIV.eval(&[&ConstBaseVal::from(7i32), &ConstBaseVal::from(0i32)]), Ok(None));
+            assert_eq!(OP_BINARY_MOD.eval(&[&ConstBaseVal::from(7u32), &ConstBaseVal::from(0u32)]), Ok(None));
+            assert_eq!(OP_BINARY_MOD.eval(&[&ConstBaseVal::from(7i32), &ConstBaseVal::from(3i32)]), Ok(Some(ConstBaseVal::from(1i32))));
+        }
+
+        #[test]
+        fn op_binary_comparison_and_logical_fold() {
+            assert_eq!(OP_BINARY_LT.eval(&[&ConstBaseVal::from(1i32), &ConstBaseVal::from(2.0f32)]), Ok(Some(ConstBaseVal::from(true))));
+            assert_eq!(OP_BINARY_GTE.eval(&[&ConstBaseVal::from(2.0f64), &ConstBaseVal::from(2i32)]), Ok(Some(ConstBaseVal::from(true))));
+            assert_eq!(OP_BINARY_EQUAL.eval(&[&ConstBaseVal::from(Vector2::new(1.0f32, 2.0f32)), &ConstBaseVal::from(Vector2::new(1.0f32, 2.0f32))]), Ok(Some(ConstBaseVal::from(true))));
+            assert_eq!(OP_BINARY_AND.eval(&[&ConstBaseVal::from(true), &ConstBaseVal::from(false)]), Ok(Some(ConstBaseVal::from(false))));
+            assert_eq!(OP_BINARY_OR.eval(&[&ConstBaseVal::from(true), &ConstBaseVal::from(false)]), Ok(Some(ConstBaseVal::from(true))));
+        }
+
+        #[test]
+        fn op_binary_matrix_vector_products_fold() {
+            let m = ConstBaseVal::from(Matrix3::from_diagonal_element(2.0f32));
+            let v = ConstBaseVal::from(Vector3::new(1.0f32, 2.0f32, 3.0f32));
+            assert_eq!(OP_BINARY_MULT.eval(&[&m, &v]), Ok(Some(ConstBaseVal::from(Vector3::new(2.0f32, 4.0f32, 6.0f32)))));
+
+            let a = ConstBaseVal::from(Matrix2::new(1.0f32, 2.0f32, 3.0f32, 4.0f32));
+            let b = ConstBaseVal::from(Matrix2::new(1.0f32, 0.0f32, 0.0f32, 1.0f32));
+            assert_eq!(OP_BINARY_MULT.eval(&[&a, &b]), Ok(Some(a.clone())));
+
+            // Mismatched inner dimensions have no compatible overload to promote into.
+            let mat2 = ConstBaseVal::from(Matrix2::from_diagonal_element(1.0f32));
+            let vec3 = ConstBaseVal::from(Vector3::from_element(1.0f32));
+            assert_eq!(OP_BINARY_MULT.eval(&[&mat2, &vec3]), Ok(None));
+        }
+
+        #[test]
+        fn eval_ambiguous_overload_is_rejected() {
+            // (Int, Float) and (Float, Int) are each one implicit cast away from (Int, Int), so
+            // calling with two ints ties between them instead of arbitrarily picking one.
+            let func = ConstEvalFunctionBuilder::new()
+                .add_overload_2(|_: i32, _: f32| Some(1i32))
+                .add_overload_2(|_: f32, _: i32| Some(2i32))
+                .build();
+
+            let a = ConstBaseVal::from(3i32);
+            let b = ConstBaseVal::from(4i32);
+            assert_eq!(func.eval(&[&a, &b]), Err(ConstEvalError::AmbiguousFunctionOverload));
+        }
+
+        #[test]
+        fn eval_prefers_lowest_conversion_cost_overload() {
+            // (UInt, UInt) is a cheaper match for two ints than (Float, Float), so it must win
+            // even though both are compatible via implicit casts.
+            let func = ConstEvalFunctionBuilder::new()
+                .add_overload_2(|_: f32, _: f32| Some(1i32))
+                .add_overload_2(|_: u32, _: u32| Some(2i32))
+                .build();
+
+            let a = ConstBaseVal::from(3i32);
+            let b = ConstBaseVal::from(4i32);
+            assert_eq!(func.eval(&[&a, &b]), Ok(Some(ConstBaseVal::from(2i32))));
+        }
+
+        #[test]
+        fn const_eval_function_instance_3ary_compatibility() {
+            type S = BaseTypeShape;
+            type T = ParameterBaseType;
+
+            let func = ConstEvalFunctionInstance::from_fn_3(|a: f32, b: f32, c: f32| Some(a.mul_add(b, c)));
+            assert!(!func.compatible_with(&[(S::Scalar, T::Float), (S::Scalar, T::Float)]));
+            assert!(func.compatible_with(&[(S::Scalar, T::Float), (S::Scalar, T::Float), (S::Scalar, T::Float)]));
+            assert!(func.compatible_with(&[(S::Scalar, T::Int), (S::Scalar, T::Int), (S::Scalar, T::Int)]));
+            assert!(!func.compatible_with(&[(S::Scalar, T::Bool), (S::Scalar, T::Float), (S::Scalar, T::Float)]));
+        }
+
+        #[test]
+        fn std_clamp_folds() {
+            let clamp = std_clamp();
+            let x = ConstBaseVal::from(1.5f32);
+            let min = ConstBaseVal::from(0.0f32);
+            let max = ConstBaseVal::from(1.0f32);
+            assert_eq!(clamp.eval(&[&x, &min, &max]), Ok(Some(ConstBaseVal::from(1.0f32))));
+
+            let x = ConstBaseVal::from(Vector3::new(-1.0f32, 0.5f32, 2.0f32));
+            assert_eq!(clamp.eval(&[&x, &min, &max]), Ok(Some(ConstBaseVal::from(Vector3::new(0.0f32, 0.5f32, 1.0f32)))));
+        }
+
+        #[test]
+        fn std_mix_folds() {
+            let mix = std_mix();
+            let x = ConstBaseVal::from(0.0f32);
+            let y = ConstBaseVal::from(10.0f32);
+            let a = ConstBaseVal::from(0.25f32);
+            assert_eq!(mix.eval(&[&x, &y, &a]), Ok(Some(ConstBaseVal::from(2.5f32))));
+        }
+
+        #[test]
+        fn std_length_distance_dot_cross_fold() {
+            let length = std_length();
+            assert_eq!(length.eval(&[&ConstBaseVal::from(Vector3::new(3.0f32, 4.0f32, 0.0f32))]), Ok(Some(ConstBaseVal::from(5.0f32))));
+
+            let distance = std_distance();
+            let a = ConstBaseVal::from(Vector2::new(1.0f32, 1.0f32));
+            let b = ConstBaseVal::from(Vector2::new(4.0f32, 5.0f32));
+            assert_eq!(distance.eval(&[&a, &b]), Ok(Some(ConstBaseVal::from(5.0f32))));
+
+            let dot = std_dot();
+            let x = ConstBaseVal::from(Vector3::new(1.0f32, 2.0f32, 3.0f32));
+            let y = ConstBaseVal::from(Vector3::new(4.0f32, 5.0f32, 6.0f32));
+            assert_eq!(dot.eval(&[&x, &y]), Ok(Some(ConstBaseVal::from(32.0f32))));
+
+            let cross = std_cross();
+            let i = ConstBaseVal::from(Vector3::new(1.0f32, 0.0f32, 0.0f32));
+            let j = ConstBaseVal::from(Vector3::new(0.0f32, 1.0f32, 0.0f32));
+            assert_eq!(cross.eval(&[&i, &j]), Ok(Some(ConstBaseVal::from(Vector3::new(0.0f32, 0.0f32, 1.0f32)))));
+        }
+
+        #[test]
+        fn std_transpose_and_matrix_comp_mult_fold() {
+            let a = Matrix2x3::new(1.0f32, 2.0f32, 3.0f32, 4.0f32, 5.0f32, 6.0f32);
+            let transpose = std_transpose();
+            assert_eq!(transpose.eval(&[&ConstBaseVal::from(a)]), Ok(Some(ConstBaseVal::from(a.transpose()))));
+
+            let b = Matrix2::new(1.0f32, 2.0f32, 3.0f32, 4.0f32);
+            let c = Matrix2::new(2.0f32, 0.0f32, 0.0f32, 2.0f32);
+            let matrix_comp_mult = std_matrix_comp_mult();
+            assert_eq!(matrix_comp_mult.eval(&[&ConstBaseVal::from(b), &ConstBaseVal::from(c)]), Ok(Some(ConstBaseVal::from(Matrix2::new(2.0f32, 0.0f32, 0.0f32, 8.0f32)))));
+        }
+
+        #[test]
+        fn std_determinant_and_inverse_fold() {
+            let m = Matrix2::new(2.0f32, 0.0f32, 0.0f32, 2.0f32);
+            let determinant = std_determinant();
+            assert_eq!(determinant.eval(&[&ConstBaseVal::from(m)]), Ok(Some(ConstBaseVal::from(4.0f32))));
+
+            let inverse = std_inverse();
+            assert_eq!(inverse.eval(&[&ConstBaseVal::from(m)]), Ok(Some(ConstBaseVal::from(Matrix2::new(0.5f32, 0.0f32, 0.0f32, 0.5f32)))));
+
+            let singular = Matrix2::new(1.0f32, 2.0f32, 2.0f32, 4.0f32);
+            assert_eq!(determinant.eval(&[&ConstBaseVal::from(singular)]), Ok(Some(ConstBaseVal::from(0.0f32))));
+            assert_eq!(inverse.eval(&[&ConstBaseVal::from(singular)]), Ok(None));
         }
     }
 }
@@ -2434,7 +4149,7 @@ mod tests {
 
     impl ConstLookup for EmptyConstLookup {
         fn lookup_const(&self, ident: &Identifier) -> Option<&ConstVal> {
-            todo!()
+            None
         }
     }
 
@@ -2448,4 +4163,139 @@ mod tests {
             assert_eq!(const_propagate_expr(case, &EmptyConstLookup(), &super::function::BUILTIN_CONST_FUNCTIONS), expected);
         }
     }
+
+    fn call(name: &str, args: Vec<Expr>) -> Expr {
+        Expr::FunCall(FunIdentifier::Identifier(Identifier::new(name).unwrap()), args)
+    }
+
+    #[test]
+    fn const_propagate_expr_vec_constructor_splat() {
+        // A single scalar argument splats to fill every component.
+        let case = call("vec3", vec![Expr::FloatConst(1.0)]);
+        let expected = Ok(ConstBaseVal::from(Vector3::from_element(1.0f32)).into());
+        assert_eq!(const_propagate_expr(&case, &EmptyConstLookup(), &super::function::BUILTIN_CONST_FUNCTIONS), expected);
+    }
+
+    #[test]
+    fn const_propagate_expr_vec_constructor_concatenation() {
+        // Multiple vector arguments are concatenated component-by-component.
+        let case = call("vec4", vec![
+            call("vec2", vec![Expr::FloatConst(1.0), Expr::FloatConst(2.0)]),
+            call("vec2", vec![Expr::FloatConst(3.0), Expr::FloatConst(4.0)]),
+        ]);
+        let expected = Ok(ConstBaseVal::from(Vector4::new(1.0f32, 2.0f32, 3.0f32, 4.0f32)).into());
+        assert_eq!(const_propagate_expr(&case, &EmptyConstLookup(), &super::function::BUILTIN_CONST_FUNCTIONS), expected);
+    }
+
+    #[test]
+    fn const_propagate_expr_mat_constructor_diagonal() {
+        // A single scalar argument forms the diagonal of a matrix, zero elsewhere.
+        let case = call("mat3", vec![Expr::FloatConst(1.0)]);
+        let expected = Ok(ConstBaseVal::from(Matrix3::from_diagonal_element(1.0f32)).into());
+        assert_eq!(const_propagate_expr(&case, &EmptyConstLookup(), &super::function::BUILTIN_CONST_FUNCTIONS), expected);
+    }
+
+    #[test]
+    fn const_propagate_expr_mat_constructor_truncation() {
+        // Constructing from a larger matrix truncates to the top-left overlap.
+        let mat4 = call("mat4", (1..=16).map(|v| Expr::FloatConst(v as f32)).collect());
+        let case = call("mat3", vec![mat4]);
+        let expected = Ok(ConstBaseVal::from(Matrix3::new(1.0f32, 5.0f32, 9.0f32, 2.0f32, 6.0f32, 10.0f32, 3.0f32, 7.0f32, 11.0f32)).into());
+        assert_eq!(const_propagate_expr(&case, &EmptyConstLookup(), &super::function::BUILTIN_CONST_FUNCTIONS), expected);
+    }
+
+    #[test]
+    fn const_propagate_expr_mat_constructor_identity_fill() {
+        // Constructing from a smaller matrix copies the overlap and fills the rest from the identity.
+        let mat2 = call("mat2", vec![Expr::FloatConst(2.0), Expr::FloatConst(3.0), Expr::FloatConst(4.0), Expr::FloatConst(5.0)]);
+        let case = call("mat3", vec![mat2]);
+        let expected = Ok(ConstBaseVal::from(Matrix3::new(2.0f32, 4.0f32, 0.0f32, 3.0f32, 5.0f32, 0.0f32, 0.0f32, 0.0f32, 1.0f32)).into());
+        assert_eq!(const_propagate_expr(&case, &EmptyConstLookup(), &super::function::BUILTIN_CONST_FUNCTIONS), expected);
+    }
+
+    #[test]
+    fn const_propagate_expr_vec_constructor_cross_type_conversion() {
+        // Constructing from a vector of a different base type converts component-by-component.
+        let case = call("ivec3", vec![call("uvec3", vec![Expr::UIntConst(1), Expr::UIntConst(2), Expr::UIntConst(3)])]);
+        let expected = Ok(ConstBaseVal::from(Vector3::new(1i32, 2i32, 3i32)).into());
+        assert_eq!(const_propagate_expr(&case, &EmptyConstLookup(), &super::function::BUILTIN_CONST_FUNCTIONS), expected);
+
+        let case = call("vec4", vec![call("ivec4", (1..=4).map(Expr::IntConst).collect())]);
+        let expected = Ok(ConstBaseVal::from(Vector4::new(1.0f32, 2.0f32, 3.0f32, 4.0f32)).into());
+        assert_eq!(const_propagate_expr(&case, &EmptyConstLookup(), &super::function::BUILTIN_CONST_FUNCTIONS), expected);
+    }
+
+    #[test]
+    fn const_propagate_expr_mat_constructor_half_conversion() {
+        // Constructing from a `float16_t` matrix converts component-by-component, same as the
+        // existing `Float`/`Double` source matrices.
+        let f16mat2 = call("f16mat2", vec![Expr::FloatConst(1.0), Expr::FloatConst(2.0), Expr::FloatConst(3.0), Expr::FloatConst(4.0)]);
+        let case = call("mat2", vec![f16mat2]);
+        let expected = Ok(ConstBaseVal::from(Matrix2::new(1.0f32, 3.0f32, 2.0f32, 4.0f32)).into());
+        assert_eq!(const_propagate_expr(&case, &EmptyConstLookup(), &super::function::BUILTIN_CONST_FUNCTIONS), expected);
+    }
+
+    #[test]
+    fn constant_manager_interns_equal_values() {
+        let mut manager = ConstantManager::new();
+        let a = manager.intern(ConstBaseVal::new_float(1.0f32));
+        let b = manager.intern(ConstBaseVal::new_float(1.0f32));
+        let c = manager.intern(ConstBaseVal::new_float(2.0f32));
+
+        assert!(a.ptr_eq(&b));
+        assert!(!a.ptr_eq(&c));
+        assert_eq!(a.as_val(), &ConstBaseVal::new_float(1.0f32));
+    }
+
+    #[test]
+    fn constant_manager_dedup_matches_partial_eq() {
+        let mut manager = ConstantManager::new();
+
+        // `+0.0 == -0.0`, so they must intern to the same allocation.
+        let pos_zero = manager.intern(ConstBaseVal::new_float(0.0f32));
+        let neg_zero = manager.intern(ConstBaseVal::new_float(-0.0f32));
+        assert!(pos_zero.ptr_eq(&neg_zero));
+
+        // Distinct NaN bit patterns share the same Debug/display representation ("NaN") but are
+        // distinct values, and must not be collapsed into one allocation.
+        let nan_a = manager.intern(ConstBaseVal::new_float(f32::NAN));
+        let nan_b = manager.intern(ConstBaseVal::new_float(f32::from_bits(f32::NAN.to_bits() | 1)));
+        assert!(!nan_a.ptr_eq(&nan_b));
+    }
+
+    struct SingleVarLookup(Identifier, ConstVal);
+
+    impl ConstLookup for SingleVarLookup {
+        fn lookup_const(&self, ident: &Identifier) -> Option<&ConstVal> {
+            if ident.0 == self.0.0 { Some(&self.1) } else { None }
+        }
+    }
+
+    #[test]
+    fn const_propagate_expr_array_length_and_index() {
+        let array = ConstArray {
+            type_specifier: TypeSpecifierNonArray::Float,
+            dims: vec![3].into_boxed_slice(),
+            data: vec![ConstBaseVal::new_float(1.0f32).into(), ConstBaseVal::new_float(2.0f32).into(), ConstBaseVal::new_float(3.0f32).into()].into_boxed_slice(),
+        };
+        let name = Identifier::new("arr").unwrap();
+        let lookup = SingleVarLookup(name.clone(), ConstVal::Array(array));
+
+        // `arr.length()` folds to the outermost dimension's size.
+        let length_expr = Expr::FunCall(
+            FunIdentifier::Expr(Box::new(Expr::Dot(Box::new(Expr::Variable(name.clone())), Identifier::new("length").unwrap()))),
+            Vec::new(),
+        );
+        assert_eq!(
+            const_propagate_expr(&length_expr, &lookup, &super::function::BUILTIN_CONST_FUNCTIONS),
+            Ok(ConstBaseVal::new_int(3).into())
+        );
+
+        // `arr[1]` folds to the element at that index.
+        let index_expr = Expr::Bracket(Box::new(Expr::Variable(name)), Box::new(Expr::UIntConst(1)));
+        assert_eq!(
+            const_propagate_expr(&index_expr, &lookup, &super::function::BUILTIN_CONST_FUNCTIONS),
+            Ok(ConstBaseVal::new_float(2.0f32).into())
+        );
+    }
 }
\ No newline at end of file
@@ -0,0 +1,424 @@
+//! A typed high level IR sitting in front of the const evaluator.
+//!
+//! Unlike [`super::const_eval`], which works directly on the untyped `glsl` AST, this pass
+//! resolves identifiers to their declared types, tracks struct layouts and builds per-name
+//! function overload sets. This lets later passes (const evaluation, codegen) work against a
+//! single concrete overload/type per node instead of re-deriving it from scratch.
+
+use std::collections::HashMap;
+
+use glsl::syntax::{
+    Declaration, Expr, ExternalDeclaration, FunIdentifier, FunctionParameterDeclaration,
+    FunctionPrototype, Identifier, InitDeclaratorList, StructFieldSpecifier, StructSpecifier,
+    TranslationUnit, TypeSpecifierNonArray, UnaryOp,
+};
+
+use super::const_eval::BaseTypeShape;
+
+/// The scalar kind of a resolved HIR type, independent of its vector/matrix shape.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub enum ScalarKind {
+    Bool,
+    Int,
+    UInt,
+    Float,
+    Double,
+}
+
+/// A fully resolved scalar/vector/matrix type as understood by the HIR.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct HirType {
+    pub scalar: ScalarKind,
+    pub shape: BaseTypeShape,
+}
+
+impl HirType {
+    pub fn new(scalar: ScalarKind, shape: BaseTypeShape) -> Self {
+        Self { scalar, shape }
+    }
+
+    /// Resolves a basic (non-struct, non-array) type specifier to its HIR type, if it names one
+    /// of the builtin scalar/vector/matrix types.
+    fn from_type_specifier_non_array(ty: &TypeSpecifierNonArray) -> Option<Self> {
+        use BaseTypeShape::*;
+        use ScalarKind::*;
+
+        let (scalar, shape) = match ty {
+            TypeSpecifierNonArray::Bool => (Bool, Scalar),
+            TypeSpecifierNonArray::Int => (Int, Scalar),
+            TypeSpecifierNonArray::UInt => (UInt, Scalar),
+            TypeSpecifierNonArray::Float => (Float, Scalar),
+            TypeSpecifierNonArray::Double => (Double, Scalar),
+            TypeSpecifierNonArray::BVec2 => (Bool, Vec2),
+            TypeSpecifierNonArray::BVec3 => (Bool, Vec3),
+            TypeSpecifierNonArray::BVec4 => (Bool, Vec4),
+            TypeSpecifierNonArray::IVec2 => (Int, Vec2),
+            TypeSpecifierNonArray::IVec3 => (Int, Vec3),
+            TypeSpecifierNonArray::IVec4 => (Int, Vec4),
+            TypeSpecifierNonArray::UVec2 => (UInt, Vec2),
+            TypeSpecifierNonArray::UVec3 => (UInt, Vec3),
+            TypeSpecifierNonArray::UVec4 => (UInt, Vec4),
+            TypeSpecifierNonArray::Vec2 => (Float, Vec2),
+            TypeSpecifierNonArray::Vec3 => (Float, Vec3),
+            TypeSpecifierNonArray::Vec4 => (Float, Vec4),
+            TypeSpecifierNonArray::DVec2 => (Double, Vec2),
+            TypeSpecifierNonArray::DVec3 => (Double, Vec3),
+            TypeSpecifierNonArray::DVec4 => (Double, Vec4),
+            TypeSpecifierNonArray::Mat2 => (Float, Mat2),
+            TypeSpecifierNonArray::Mat23 => (Float, Mat23),
+            TypeSpecifierNonArray::Mat24 => (Float, Mat24),
+            TypeSpecifierNonArray::Mat32 => (Float, Mat32),
+            TypeSpecifierNonArray::Mat3 => (Float, Mat3),
+            TypeSpecifierNonArray::Mat34 => (Float, Mat34),
+            TypeSpecifierNonArray::Mat42 => (Float, Mat42),
+            TypeSpecifierNonArray::Mat43 => (Float, Mat43),
+            TypeSpecifierNonArray::Mat4 => (Float, Mat4),
+            TypeSpecifierNonArray::DMat2 => (Double, Mat2),
+            TypeSpecifierNonArray::DMat23 => (Double, Mat23),
+            TypeSpecifierNonArray::DMat24 => (Double, Mat24),
+            TypeSpecifierNonArray::DMat32 => (Double, Mat32),
+            TypeSpecifierNonArray::DMat3 => (Double, Mat3),
+            TypeSpecifierNonArray::DMat34 => (Double, Mat34),
+            TypeSpecifierNonArray::DMat42 => (Double, Mat42),
+            TypeSpecifierNonArray::DMat43 => (Double, Mat43),
+            TypeSpecifierNonArray::DMat4 => (Double, Mat4),
+            _ => return None,
+        };
+        Some(Self::new(scalar, shape))
+    }
+}
+
+/// A single function overload signature: its parameter types in order.
+pub type OverloadSignature = Box<[HirType]>;
+
+/// The set of overloads declared for a given function name, each keyed by its parameter type
+/// signature.
+#[derive(Clone, Debug, Default)]
+pub struct OverloadSet {
+    overloads: Vec<(OverloadSignature, Option<HirType>)>,
+}
+
+impl OverloadSet {
+    fn add(&mut self, signature: OverloadSignature, return_type: Option<HirType>) {
+        self.overloads.push((signature, return_type));
+    }
+
+    /// Finds the overload whose signature exactly matches the provided argument types. Real GLSL
+    /// implicit-cast-aware resolution is left to the const evaluator's own overload machinery;
+    /// this is only used to disambiguate user-defined functions by arity/type during lowering.
+    pub fn find_exact(&self, args: &[HirType]) -> Option<&(OverloadSignature, Option<HirType>)> {
+        self.overloads.iter().find(|(sig, _)| sig.as_ref() == args)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub enum HirError {
+    UnknownIdentifier(String),
+    UnknownStructureMember(String),
+    UnsupportedTypeSpecifier,
+    IllegalUnaryOperand(UnaryOp),
+    TypeMismatch,
+    NoMatchingOverload(String),
+}
+
+/// A single lexically scoped frame mapping identifiers to their resolved HIR type.
+struct Scope {
+    bindings: HashMap<String, HirType>,
+}
+
+/// Typed HIR expression node. Every node carries the concrete [`HirType`] it was resolved to.
+#[derive(Clone, Debug)]
+pub enum HirExpr {
+    Variable(Identifier, HirType),
+    IntConst(i32),
+    UIntConst(u32),
+    BoolConst(bool),
+    FloatConst(f32),
+    DoubleConst(f64),
+    Unary(UnaryOp, Box<HirExpr>, HirType),
+    Binary(glsl::syntax::BinaryOp, Box<HirExpr>, Box<HirExpr>, HirType),
+    /// A call to a resolved overload of a user defined or builtin (constructor) function.
+    FunCall(Identifier, Vec<HirExpr>, HirType),
+    /// Member access; the resolved type is only known for swizzles of scalar/vector values.
+    /// Struct field types are not resolved.
+    Member(Box<HirExpr>, Identifier, Option<HirType>),
+}
+
+impl HirExpr {
+    pub fn ty(&self) -> Option<HirType> {
+        match self {
+            HirExpr::Variable(_, ty) => Some(*ty),
+            HirExpr::IntConst(_) => Some(HirType::new(ScalarKind::Int, BaseTypeShape::Scalar)),
+            HirExpr::UIntConst(_) => Some(HirType::new(ScalarKind::UInt, BaseTypeShape::Scalar)),
+            HirExpr::BoolConst(_) => Some(HirType::new(ScalarKind::Bool, BaseTypeShape::Scalar)),
+            HirExpr::FloatConst(_) => Some(HirType::new(ScalarKind::Float, BaseTypeShape::Scalar)),
+            HirExpr::DoubleConst(_) => Some(HirType::new(ScalarKind::Double, BaseTypeShape::Scalar)),
+            HirExpr::Unary(_, _, ty) => Some(*ty),
+            HirExpr::Binary(_, _, _, ty) => Some(*ty),
+            HirExpr::FunCall(_, _, ty) => Some(*ty),
+            HirExpr::Member(_, _, ty) => *ty,
+        }
+    }
+}
+
+/// Builds a typed HIR from a parsed `TranslationUnit`.
+///
+/// Struct specifiers and function overload sets are recorded globally as they are encountered,
+/// matching GLSL's single-pass visibility rules (a function may only call overloads declared
+/// above it in the unit). Function bodies are not walked: a definition only records its
+/// prototype, and scopes are only pushed/popped through [`Self::push_scope`]/[`Self::pop_scope`].
+pub struct HirBuilder {
+    scopes: Vec<Scope>,
+    structs: HashMap<String, StructSpecifier>,
+    overloads: HashMap<String, OverloadSet>,
+}
+
+impl HirBuilder {
+    pub fn new() -> Self {
+        Self {
+            scopes: vec![Scope { bindings: HashMap::new() }],
+            structs: HashMap::new(),
+            overloads: HashMap::new(),
+        }
+    }
+
+    pub fn push_scope(&mut self) {
+        self.scopes.push(Scope { bindings: HashMap::new() });
+    }
+
+    pub fn pop_scope(&mut self) {
+        self.scopes.pop();
+    }
+
+    pub fn declare_variable(&mut self, name: &Identifier, ty: HirType) {
+        self.scopes.last_mut().unwrap().bindings.insert(name.0.clone(), ty);
+    }
+
+    pub fn lookup_variable(&self, name: &Identifier) -> Option<HirType> {
+        self.scopes.iter().rev().find_map(|scope| scope.bindings.get(&name.0).copied())
+    }
+
+    pub fn lookup_struct(&self, name: &str) -> Option<&StructSpecifier> {
+        self.structs.get(name)
+    }
+
+    pub fn lookup_overloads(&self, name: &str) -> Option<&OverloadSet> {
+        self.overloads.get(name)
+    }
+
+    fn record_struct(&mut self, spec: &StructSpecifier) {
+        if let Some(name) = &spec.name {
+            self.structs.insert(name.0.clone(), spec.clone());
+        }
+    }
+
+    fn record_prototype(&mut self, prototype: &FunctionPrototype) {
+        let mut signature = Vec::with_capacity(prototype.parameters.len());
+        for param in &prototype.parameters {
+            let ty = match param {
+                FunctionParameterDeclaration::Named(_, decl) => {
+                    HirType::from_type_specifier_non_array(&decl.ty.ty)
+                }
+                FunctionParameterDeclaration::Unnamed(_, ty) => {
+                    HirType::from_type_specifier_non_array(&ty.ty)
+                }
+            };
+            // Unresolvable (e.g. struct-typed) parameters still occupy a slot so arity based
+            // disambiguation keeps working; they just never match an exact-type lookup.
+            signature.push(ty.unwrap_or(HirType::new(ScalarKind::Float, BaseTypeShape::Scalar)));
+        }
+
+        let return_type = HirType::from_type_specifier_non_array(&prototype.ty.ty.ty);
+
+        self.overloads
+            .entry(prototype.name.0.clone())
+            .or_insert_with(OverloadSet::default)
+            .add(signature.into_boxed_slice(), return_type);
+    }
+
+    /// Walks every external declaration, recording struct layouts and function overload sets and
+    /// pulling top level variables of builtin scalar/vector/matrix type into scope.
+    pub fn walk_translation_unit(&mut self, unit: &TranslationUnit) {
+        for decl in &unit.0 {
+            self.walk_external_declaration(decl);
+        }
+    }
+
+    fn walk_external_declaration(&mut self, decl: &ExternalDeclaration) {
+        match decl {
+            ExternalDeclaration::Declaration(decl) => self.walk_declaration(decl),
+            ExternalDeclaration::FunctionDefinition(def) => {
+                self.record_prototype(&def.prototype);
+            }
+            ExternalDeclaration::Preprocessor(_) => {}
+        }
+    }
+
+    fn walk_declaration(&mut self, decl: &Declaration) {
+        match decl {
+            Declaration::FunctionPrototype(prototype) => self.record_prototype(prototype),
+            Declaration::InitDeclaratorList(init) => self.walk_init_declarator_list(init),
+            Declaration::Block(block) => self.record_struct_like_block(block),
+            Declaration::Global(_, _) => {}
+            _ => {}
+        }
+    }
+
+    fn walk_init_declarator_list(&mut self, init: &InitDeclaratorList) {
+        let declared_ty = HirType::from_type_specifier_non_array(&init.head.ty.ty.ty);
+        if let Some(ty) = declared_ty {
+            if let Some(name) = &init.head.name {
+                self.declare_variable(name, ty);
+            }
+            for tail in &init.tail {
+                self.declare_variable(&tail.ident.ident, ty);
+            }
+        }
+
+        if let TypeSpecifierNonArray::Struct(spec) = &init.head.ty.ty.ty {
+            self.record_struct(spec);
+        }
+    }
+
+    fn record_struct_like_block(&mut self, block: &glsl::syntax::Block) {
+        // Interface blocks declare an implicit struct-like type; record its fields the same way
+        // as a named struct so member lookups still resolve.
+        let spec = StructSpecifier {
+            name: Some(block.name.clone()),
+            fields: block.fields.clone(),
+        };
+        self.record_struct(&spec);
+    }
+
+    /// Lowers an expression to a typed HIR node, resolving variable/member/overload references
+    /// against the current scope.
+    pub fn lower_expr(&self, expr: &Expr) -> Result<HirExpr, HirError> {
+        match expr {
+            Expr::Variable(ident) => {
+                let ty = self
+                    .lookup_variable(ident)
+                    .ok_or_else(|| HirError::UnknownIdentifier(ident.0.clone()))?;
+                Ok(HirExpr::Variable(ident.clone(), ty))
+            }
+            Expr::IntConst(v) => Ok(HirExpr::IntConst(*v)),
+            Expr::UIntConst(v) => Ok(HirExpr::UIntConst(*v)),
+            Expr::BoolConst(v) => Ok(HirExpr::BoolConst(*v)),
+            Expr::FloatConst(v) => Ok(HirExpr::FloatConst(*v)),
+            Expr::DoubleConst(v) => Ok(HirExpr::DoubleConst(*v)),
+            Expr::Unary(op, a) => {
+                let a = self.lower_expr(a)?;
+                let ty = a.ty().ok_or_else(|| HirError::IllegalUnaryOperand(op.clone()))?;
+                Ok(HirExpr::Unary(op.clone(), Box::new(a), ty))
+            }
+            Expr::Binary(op, a, b) => {
+                let a = self.lower_expr(a)?;
+                let b = self.lower_expr(b)?;
+                let (a_ty, b_ty) = (a.ty(), b.ty());
+                let ty = match (a_ty, b_ty) {
+                    (Some(a_ty), Some(b_ty)) if a_ty.shape == b_ty.shape => a_ty.max(b_ty),
+                    (Some(a_ty), Some(b_ty)) if a_ty.shape == BaseTypeShape::Scalar => b_ty,
+                    (Some(a_ty), Some(b_ty)) if b_ty.shape == BaseTypeShape::Scalar => a_ty,
+                    _ => return Err(HirError::TypeMismatch),
+                };
+                Ok(HirExpr::Binary(op.clone(), Box::new(a), Box::new(b), ty))
+            }
+            Expr::FunCall(FunIdentifier::Identifier(ident), params) => {
+                let args = params.iter().map(|p| self.lower_expr(p)).collect::<Result<Vec<_>, _>>()?;
+                let arg_types: Vec<HirType> = args.iter().filter_map(HirExpr::ty).collect();
+
+                let ty = match self.lookup_overloads(&ident.0) {
+                    Some(set) => match set.find_exact(&arg_types) {
+                        Some((_, ret)) => ret.unwrap_or(HirType::new(ScalarKind::Float, BaseTypeShape::Scalar)),
+                        None => return Err(HirError::NoMatchingOverload(ident.0.clone())),
+                    },
+                    // No recorded user overload: assume a builtin type constructor/function and
+                    // infer its shape from the name, falling back to the first argument's type.
+                    None => TypeSpecifierNonArray::parse(&ident.0)
+                        .and_then(|ty| HirType::from_type_specifier_non_array(&ty))
+                        .or_else(|| arg_types.first().copied())
+                        .unwrap_or(HirType::new(ScalarKind::Float, BaseTypeShape::Scalar)),
+                };
+
+                Ok(HirExpr::FunCall(ident.clone(), args, ty))
+            }
+            Expr::FunCall(FunIdentifier::Expr(_), _) => Err(HirError::TypeMismatch),
+            Expr::Dot(a, ident) => {
+                let a = self.lower_expr(a)?;
+                let field_ty = match a.ty() {
+                    Some(ty) if ty.shape.is_scalar() || ty.shape.is_vector() => {
+                        // Swizzle: shares the base's scalar kind, with one component per letter.
+                        let shape = match ident.0.len() {
+                            1 => Some(BaseTypeShape::Scalar),
+                            2 => Some(BaseTypeShape::Vec2),
+                            3 => Some(BaseTypeShape::Vec3),
+                            4 => Some(BaseTypeShape::Vec4),
+                            _ => None,
+                        };
+                        shape.map(|shape| HirType::new(ty.scalar, shape))
+                    }
+                    _ => None,
+                };
+                Ok(HirExpr::Member(Box::new(a), ident.clone(), field_ty))
+            }
+            _ => Err(HirError::TypeMismatch),
+        }
+    }
+}
+
+impl HirType {
+    /// Picks the "wider" of two same-shape types per GLSL implicit promotion, used only to give
+    /// binary expressions a sensible resulting type during HIR lowering.
+    fn max(self, other: Self) -> Self {
+        use ScalarKind::*;
+        let rank = |s: ScalarKind| match s {
+            Bool => 0,
+            Int => 1,
+            UInt => 2,
+            Float => 3,
+            Double => 4,
+        };
+        if rank(other.scalar) > rank(self.scalar) {
+            HirType::new(other.scalar, self.shape)
+        } else {
+            self
+        }
+    }
+}
+
+/// Minimal helper used while resolving unqualified function-call identifiers against the set of
+/// builtin type names, so a call like `vec3(...)` gets a concrete HIR type even when it has no
+/// recorded user overload.
+trait ParseTypeSpecifierNonArray: Sized {
+    fn parse(name: &str) -> Option<Self>;
+}
+
+impl ParseTypeSpecifierNonArray for TypeSpecifierNonArray {
+    fn parse(name: &str) -> Option<Self> {
+        Some(match name {
+            "bool" => TypeSpecifierNonArray::Bool,
+            "int" => TypeSpecifierNonArray::Int,
+            "uint" => TypeSpecifierNonArray::UInt,
+            "float" => TypeSpecifierNonArray::Float,
+            "double" => TypeSpecifierNonArray::Double,
+            "bvec2" => TypeSpecifierNonArray::BVec2,
+            "bvec3" => TypeSpecifierNonArray::BVec3,
+            "bvec4" => TypeSpecifierNonArray::BVec4,
+            "ivec2" => TypeSpecifierNonArray::IVec2,
+            "ivec3" => TypeSpecifierNonArray::IVec3,
+            "ivec4" => TypeSpecifierNonArray::IVec4,
+            "uvec2" => TypeSpecifierNonArray::UVec2,
+            "uvec3" => TypeSpecifierNonArray::UVec3,
+            "uvec4" => TypeSpecifierNonArray::UVec4,
+            "vec2" => TypeSpecifierNonArray::Vec2,
+            "vec3" => TypeSpecifierNonArray::Vec3,
+            "vec4" => TypeSpecifierNonArray::Vec4,
+            "dvec2" => TypeSpecifierNonArray::DVec2,
+            "dvec3" => TypeSpecifierNonArray::DVec3,
+            "dvec4" => TypeSpecifierNonArray::DVec4,
+            "mat2" => TypeSpecifierNonArray::Mat2,
+            "mat3" => TypeSpecifierNonArray::Mat3,
+            "mat4" => TypeSpecifierNonArray::Mat4,
+            _ => return None,
+        })
+    }
+}
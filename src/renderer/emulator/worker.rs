@@ -1,7 +1,10 @@
 use std::cell::RefCell;
+use std::collections::HashMap;
 use std::marker::PhantomData;
 use std::rc::Rc;
-use std::sync::Arc;
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::{Arc, Mutex};
+use std::thread;
 use std::time::Duration;
 
 use ash::prelude::VkResult;
@@ -23,110 +26,415 @@ use crate::renderer::emulator::share::{NextTaskResult, Share};
 
 pub(super) enum WorkerTask {
     StartPass(PassId, Arc<dyn EmulatorPipeline>, Box<dyn EmulatorPipelinePass + Send>, vk::ImageView, StaticImageId),
-    EndPass(Box<ImmediateBuffer>),
-    UseStaticMesh(StaticMeshId),
-    UseStaticImage(StaticImageId),
-    UseShader(ShaderId),
-    UseOutput(Box<dyn EmulatorOutput + Send>),
-    PipelineTask(PipelineTask),
+    EndPass(PassId, Box<ImmediateBuffer>),
+    UseStaticMesh(PassId, StaticMeshId),
+    UseStaticImage(PassId, StaticImageId),
+    UseShader(PassId, ShaderId),
+    UseOutput(PassId, Box<dyn EmulatorOutput + Send>),
+    PipelineTask(PassId, PipelineTask),
+    BuildAccelerationStructures(PassId, AccelerationStructureBuildTask),
 }
 
-pub(super) fn run_worker(device: Arc<DeviceContext>, share: Arc<Share>) {
-    let queue = device.get_main_queue();
+/// A bottom- and top-level acceleration structure build (`VK_KHR_acceleration_structure`) to
+/// record for a pass, built over the geometry of `meshes` (which must already have been pulled
+/// into the pass via `WorkerTask::UseStaticMesh`).
+pub(super) struct AccelerationStructureBuildTask {
+    pub meshes: Vec<StaticMeshId>,
+    pub mode: AccelerationStructureBuildMode,
+}
 
-    let pool = Rc::new(RefCell::new(WorkerObjectPool::new(device.clone(), queue.get_queue_family_index())));
-    let mut current_pass: Option<PassState> = None;
-    let mut old_frames = Vec::new();
+pub(super) enum AccelerationStructureBuildMode {
+    /// Build fresh bottom- and top-level acceleration structures, preferring trace performance
+    /// over build speed. Owned by the pass: torn down in [`PassState::drop`] once its GPU work
+    /// completes, the same way it already releases the static mesh references it was built from.
+    Build,
+    /// Refit `tlas` in place over the existing `blas` instead of building from scratch, e.g.
+    /// because only instance transforms changed since it was built. Uses the much smaller
+    /// update-scratch size. `blas`/`tlas` are assumed to already be owned by whoever is caching
+    /// them across passes (see [`BuiltAccelerationStructure`]), so this pass does not destroy
+    /// them when it is dropped.
+    Update {
+        blas: Vec<BuiltAccelerationStructure>,
+        tlas: BuiltAccelerationStructure,
+    },
+}
 
-    let queue = device.get_main_queue();
+/// A top-level or bottom-level acceleration structure plus the buffer backing it. Structures
+/// built fresh (via [`AccelerationStructureBuildMode::Build`]) are destroyed by
+/// [`PassState::drop`] once the pass' GPU work has completed. A pipeline that instead wants to
+/// refit the same structures across many passes must retrieve this value itself (e.g. from its
+/// ray-traced [`EmulatorOutput`]) before the owning pass is dropped, and feed it back in as
+/// [`AccelerationStructureBuildMode::Update`] on a later pass — at which point this module no
+/// longer manages its lifetime.
+#[derive(Copy, Clone)]
+pub struct BuiltAccelerationStructure {
+    pub acceleration_structure: vk::AccelerationStructureKHR,
+    pub buffer: vk::Buffer,
+}
+
+/// Geometry for a single acceleration structure build, as provided by [`Share`] (which owns the
+/// underlying vertex/index/instance buffers the geometry's device addresses point into).
+pub struct AccelerationStructureGeometry {
+    pub info: vk::AccelerationStructureGeometryKHR,
+    pub range: vk::AccelerationStructureBuildRangeInfoKHR,
+}
+
+/// The acceleration structures a single [`WorkerTask::BuildAccelerationStructures`] produced for
+/// a pass: one bottom-level structure per mesh, plus the top-level structure built over them.
+struct PassAccelerationStructureBuild {
+    blas: Vec<BuiltAccelerationStructure>,
+    tlas: BuiltAccelerationStructure,
+    /// Whether this pass created `blas`/`tlas` (and so must destroy them), as opposed to having
+    /// been handed already-owned structures to refit in
+    /// [`AccelerationStructureBuildMode::Update`].
+    owned: bool,
+}
+
+/// A point on [`Share`]'s single, monotonically-increasing timeline semaphore. Since every GPU
+/// submission signals a strictly greater value on that one semaphore, whether `N` has completed
+/// is just `N <= current counter value`, and waiting on several passes at once collapses to
+/// waiting on the greatest of their values via [`Self::join`] rather than tracking a fence per
+/// pass.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct GpuFuture(u64);
+
+impl GpuFuture {
+    /// Already-reached future, the identity element for [`Self::join`].
+    pub const NONE: GpuFuture = GpuFuture(0);
+
+    pub fn join(self, other: GpuFuture) -> GpuFuture {
+        GpuFuture(self.0.max(other.0))
+    }
+
+    pub fn join_all(futures: impl IntoIterator<Item = GpuFuture>) -> GpuFuture {
+        futures.into_iter().fold(GpuFuture::NONE, GpuFuture::join)
+    }
+
+    fn is_reached(self, current_value: u64) -> bool {
+        self.0 <= current_value
+    }
+
+    /// Blocks the calling thread until the timeline semaphore reaches this point or `timeout`
+    /// elapses, without CPU-spinning in between. Returns `false` on timeout.
+    pub fn wait(self, device: &DeviceContext, semaphore: vk::Semaphore, timeout: Duration) -> VkResult<bool> {
+        if self.0 == 0 {
+            return Ok(true);
+        }
+
+        let semaphores = [semaphore];
+        let values = [self.0];
+        let info = vk::SemaphoreWaitInfo::builder()
+            .semaphores(&semaphores)
+            .values(&values);
+
+        match unsafe {
+            device.vk().wait_semaphores(&info, timeout.as_nanos() as u64)
+        } {
+            Ok(()) => Ok(true),
+            Err(vk::Result::TIMEOUT) => Ok(false),
+            Err(err) => Err(err),
+        }
+    }
+}
+
+pub(super) fn run_worker(device: Arc<DeviceContext>, share: Arc<Share>) {
+    let mut recording_pool = RecordingThreadPool::new(device.clone(), share.clone());
 
     loop {
         share.worker_update();
 
-        old_frames.retain(|old: &PassState| {
-            !old.is_complete()
-        });
-
         let task = match share.try_get_next_task_timeout(Duration::from_micros(500)) {
             NextTaskResult::Ok(task) => task,
             NextTaskResult::Timeout => continue,
         };
 
         match task {
-            WorkerTask::StartPass(_, pipeline, pass, placeholder_image, placeholder_id) => {
-                if current_pass.is_some() {
-                    log::error!("Worker received WorkerTask::StartPass when a pass is already running");
+            WorkerTask::StartPass(id, pipeline, pass, placeholder_image, placeholder_id) => {
+                if !recording_pool.start_pass(id, pipeline, pass, placeholder_image, placeholder_id) {
+                    log::error!("Worker received WorkerTask::StartPass for a pass that is already running");
                     panic!()
                 }
-                let mut state = PassState::new(pipeline, pass, device.clone(), &queue, share.clone(), pool.clone(), placeholder_image, placeholder_id);
-                current_pass = Some(state);
             }
 
-            WorkerTask::EndPass(immediate_buffer) => {
-                if let Some(mut pass) = current_pass.take() {
-                    share.flush_global_objects();
-                    pass.use_immediate_buffer(immediate_buffer);
-                    pass.submit(&queue);
-                    old_frames.push(pass);
-                } else {
-                    log::error!("Worker received WorkerTask::EndPass when no active pass exists");
+            WorkerTask::EndPass(id, immediate_buffer) => {
+                share.flush_global_objects();
+                if !recording_pool.end_pass(id, immediate_buffer) {
+                    log::error!("Worker received WorkerTask::EndPass for an unknown pass");
                     panic!()
                 }
             }
 
-            WorkerTask::UseStaticMesh(mesh_id) => {
-                if let Some(pass) = &mut current_pass {
-                    pass.static_meshes.push(mesh_id);
-                } else {
-                    log::error!("Worker received WorkerTask::UseStaticMesh when no active pass exists");
+            WorkerTask::UseStaticMesh(id, mesh_id) => {
+                if !recording_pool.use_static_mesh(id, mesh_id) {
+                    log::error!("Worker received WorkerTask::UseStaticMesh for an unknown pass");
                     panic!()
                 }
             }
 
-            WorkerTask::UseStaticImage(image_id) => {
-                if let Some(pass) = &mut current_pass {
-                    pass.static_images.push(image_id);
-                } else {
-                    log::error!("Worker received WorkerTask::UseStaticImage when no active pass exits");
+            WorkerTask::UseStaticImage(id, image_id) => {
+                if !recording_pool.use_static_image(id, image_id) {
+                    log::error!("Worker received WorkerTask::UseStaticImage for an unknown pass");
                     panic!()
                 }
             }
 
-            WorkerTask::UseShader(shader) => {
-                if let Some(pass) = &mut current_pass {
-                    pass.shaders.push(shader);
-                } else {
-                    log::error!("Worker received WorkerTask::UseShader when no active pass exists");
+            WorkerTask::UseShader(id, shader) => {
+                if !recording_pool.use_shader(id, shader) {
+                    log::error!("Worker received WorkerTask::UseShader for an unknown pass");
                     panic!()
                 }
             }
 
-            WorkerTask::UseOutput(output) => {
-                if let Some(pass) = &mut current_pass {
-                    pass.use_output(output);
-                } else {
-                    log::error!("Worker received WorkerTask::UseOutput when no active pass exists");
+            WorkerTask::UseOutput(id, output) => {
+                if !recording_pool.use_output(id, output) {
+                    log::error!("Worker received WorkerTask::UseOutput for an unknown pass");
                     panic!()
                 }
             }
 
-            WorkerTask::PipelineTask(task) => {
-                if let Some(pass) = &mut current_pass {
-                    pass.process_task(&task)
-                } else {
-                    log::error!("Worker received WorkerTask::PipelineTask when no active pass exists");
+            WorkerTask::PipelineTask(id, task) => {
+                if !recording_pool.process_task(id, task) {
+                    log::error!("Worker received WorkerTask::PipelineTask for an unknown pass");
                     panic!()
                 }
             }
+
+            WorkerTask::BuildAccelerationStructures(id, task) => {
+                if !recording_pool.build_acceleration_structures(id, task) {
+                    log::error!("Worker received WorkerTask::BuildAccelerationStructures for an unknown pass");
+                    panic!()
+                }
+            }
+        }
+    }
+}
+
+/// Number of dedicated threads used to record passes concurrently. Each thread owns its own
+/// [`WorkerObjectPool`] (and therefore its own `vk::CommandPool`), since command pools may only
+/// ever be accessed from a single thread. This lets recording of pass N+1 proceed while pass N
+/// is still being recorded (or is executing on the GPU), instead of serializing all recording
+/// work behind a single pass at a time.
+const RECORDING_THREAD_COUNT: usize = 2;
+
+enum RecordingMessage {
+    StartPass {
+        id: PassId,
+        pipeline: Arc<dyn EmulatorPipeline>,
+        pass: Box<dyn EmulatorPipelinePass + Send>,
+        placeholder_image: vk::ImageView,
+        placeholder_id: StaticImageId,
+    },
+    UseStaticMesh(PassId, StaticMeshId),
+    UseStaticImage(PassId, StaticImageId),
+    UseShader(PassId, ShaderId),
+    UseOutput(PassId, Box<dyn EmulatorOutput + Send>),
+    PipelineTask(PassId, PipelineTask),
+    BuildAccelerationStructures(PassId, AccelerationStructureBuildTask),
+    EndPass(PassId, Box<ImmediateBuffer>),
+}
+
+/// Dispatches [`WorkerTask`]s for concurrently-recording passes across [`RECORDING_THREAD_COUNT`]
+/// recording threads, keyed by [`PassId`]. A pass is pinned to whichever thread started it for
+/// the rest of its lifetime, so per-pass state (and the `Rc<RefCell<WorkerObjectPool>>` it holds)
+/// never has to move between threads. A submitted pass' completion polling, GPU time reporting
+/// and object-pool reclaim all stay on that same recording thread instead of being handed back to
+/// the main worker thread, since `WorkerObjectPool`'s command pool and query pool may only ever be
+/// touched from the thread that created them.
+struct RecordingThreadPool {
+    senders: Vec<Sender<RecordingMessage>>,
+    pass_threads: HashMap<PassId, usize>,
+    next_thread: usize,
+}
+
+impl RecordingThreadPool {
+    fn new(device: Arc<DeviceContext>, share: Arc<Share>) -> Self {
+        let queue_family = device.get_main_queue().get_queue_family_index();
+        // `vkQueueSubmit2` requires external synchronization if the same queue may be submitted
+        // to from multiple threads, which is exactly what recording threads do once they finish
+        // recording a pass.
+        let submit_lock = Arc::new(Mutex::new(()));
+
+        let senders = (0..RECORDING_THREAD_COUNT).map(|index| {
+            let (tx, rx) = mpsc::channel();
+
+            let device = device.clone();
+            let share = share.clone();
+            let submit_lock = submit_lock.clone();
+
+            thread::Builder::new()
+                .name(format!("emulator-recording-{}", index))
+                .spawn(move || {
+                    let queue = device.get_main_queue();
+                    recording_thread_main(device, queue, queue_family, share, submit_lock, rx)
+                })
+                .unwrap();
+
+            tx
+        }).collect();
+
+        Self {
+            senders,
+            pass_threads: HashMap::new(),
+            next_thread: 0,
+        }
+    }
+
+    fn start_pass(&mut self, id: PassId, pipeline: Arc<dyn EmulatorPipeline>, pass: Box<dyn EmulatorPipelinePass + Send>, placeholder_image: vk::ImageView, placeholder_id: StaticImageId) -> bool {
+        if self.pass_threads.contains_key(&id) {
+            return false;
+        }
+
+        let thread = self.next_thread;
+        self.next_thread = (self.next_thread + 1) % self.senders.len();
+        self.pass_threads.insert(id, thread);
+
+        let _ = self.senders[thread].send(RecordingMessage::StartPass { id, pipeline, pass, placeholder_image, placeholder_id });
+
+        true
+    }
+
+    fn end_pass(&mut self, id: PassId, immediate_buffer: Box<ImmediateBuffer>) -> bool {
+        let Some(thread) = self.pass_threads.remove(&id) else {
+            return false;
+        };
+
+        let _ = self.senders[thread].send(RecordingMessage::EndPass(id, immediate_buffer));
+
+        true
+    }
+
+    fn use_static_mesh(&self, id: PassId, mesh_id: StaticMeshId) -> bool {
+        self.send_to(id, RecordingMessage::UseStaticMesh(id, mesh_id))
+    }
+
+    fn use_static_image(&self, id: PassId, image_id: StaticImageId) -> bool {
+        self.send_to(id, RecordingMessage::UseStaticImage(id, image_id))
+    }
+
+    fn use_shader(&self, id: PassId, shader: ShaderId) -> bool {
+        self.send_to(id, RecordingMessage::UseShader(id, shader))
+    }
+
+    fn use_output(&self, id: PassId, output: Box<dyn EmulatorOutput + Send>) -> bool {
+        self.send_to(id, RecordingMessage::UseOutput(id, output))
+    }
+
+    fn process_task(&self, id: PassId, task: PipelineTask) -> bool {
+        self.send_to(id, RecordingMessage::PipelineTask(id, task))
+    }
+
+    fn build_acceleration_structures(&self, id: PassId, task: AccelerationStructureBuildTask) -> bool {
+        self.send_to(id, RecordingMessage::BuildAccelerationStructures(id, task))
+    }
+
+    fn send_to(&self, id: PassId, message: RecordingMessage) -> bool {
+        let Some(thread) = self.pass_threads.get(&id) else {
+            return false;
+        };
+
+        let _ = self.senders[*thread].send(message);
+
+        true
+    }
+}
+
+fn recording_thread_main(device: Arc<DeviceContext>, queue: Queue, queue_family: u32, share: Arc<Share>, submit_lock: Arc<Mutex<()>>, rx: Receiver<RecordingMessage>) {
+    let pool = Rc::new(RefCell::new(WorkerObjectPool::new(device.clone(), queue_family)));
+    let mut passes: HashMap<PassId, PassState> = HashMap::new();
+    // Passes this thread has submitted and is waiting on the GPU to finish executing. Polled and
+    // reclaimed right here rather than on the main worker thread, since that reclaim touches this
+    // thread's `WorkerObjectPool` (command pool, query pool), which is not `Send`/`Sync`.
+    let mut submitted: Vec<PassState> = Vec::new();
+
+    loop {
+        let message = match rx.recv_timeout(Duration::from_micros(500)) {
+            Ok(message) => message,
+            Err(mpsc::RecvTimeoutError::Timeout) => {
+                reclaim_submitted(&device, &share, &mut submitted);
+                continue;
+            }
+            Err(mpsc::RecvTimeoutError::Disconnected) => break,
+        };
+
+        match message {
+            RecordingMessage::StartPass { id, pipeline, pass, placeholder_image, placeholder_id } => {
+                let state = PassState::new(id, pipeline, pass, device.clone(), &queue, share.clone(), pool.clone(), placeholder_image, placeholder_id);
+                passes.insert(id, state);
+            }
+
+            RecordingMessage::UseStaticMesh(id, mesh_id) => {
+                passes.get_mut(&id).unwrap().static_meshes.push(mesh_id);
+            }
+
+            RecordingMessage::UseStaticImage(id, image_id) => {
+                passes.get_mut(&id).unwrap().static_images.push(image_id);
+            }
+
+            RecordingMessage::UseShader(id, shader) => {
+                passes.get_mut(&id).unwrap().shaders.push(shader);
+            }
+
+            RecordingMessage::UseOutput(id, output) => {
+                passes.get_mut(&id).unwrap().use_output(output);
+            }
+
+            RecordingMessage::PipelineTask(id, task) => {
+                passes.get_mut(&id).unwrap().process_task(&task);
+            }
+
+            RecordingMessage::BuildAccelerationStructures(id, task) => {
+                passes.get_mut(&id).unwrap().build_acceleration_structures(task);
+            }
+
+            RecordingMessage::EndPass(id, immediate_buffer) => {
+                let mut state = passes.remove(&id).unwrap();
+                state.use_immediate_buffer(immediate_buffer);
+
+                {
+                    let _guard = submit_lock.lock().unwrap();
+                    state.submit(&queue);
+                }
+
+                submitted.push(state);
+            }
         }
+
+        reclaim_submitted(&device, &share, &mut submitted);
     }
 }
 
+/// Drops every pass in `submitted` whose GPU work has completed, reporting its profiled GPU time
+/// (if any) to `share` first. Must run on the thread that owns the passes' `WorkerObjectPool`, so
+/// that dropping a finished [`PassState`] reclaims its command buffers and query slot back into
+/// that same pool instead of touching it from another thread.
+fn reclaim_submitted(device: &DeviceContext, share: &Share, submitted: &mut Vec<PassState>) {
+    // A single query of the timeline semaphore's current value tells us which of the (potentially
+    // many) in-flight passes have completed, replacing a `vkGetFenceStatus` poll per pass with one
+    // cheap `vkGetSemaphoreCounterValue` call per check.
+    let timeline_value = unsafe {
+        device.vk().get_semaphore_counter_value(share.timeline_semaphore())
+    }.unwrap();
+
+    submitted.retain_mut(|pass: &mut PassState| {
+        if !pass.completion().is_reached(timeline_value) {
+            return true;
+        }
+
+        if let Some(gpu_time) = pass.resolve_gpu_time() {
+            share.report_pass_gpu_time(pass.id, gpu_time);
+        }
+
+        false
+    });
+}
+
 struct WorkerObjectPool {
     device: Arc<DeviceContext>,
     command_pool: vk::CommandPool,
     command_buffers: Vec<vk::CommandBuffer>,
-    fences: Vec<vk::Fence>,
+    query_pool: vk::QueryPool,
+    query_slots: Vec<u32>,
+    timestamp_period: f32,
 }
 
 impl WorkerObjectPool {
@@ -139,29 +447,49 @@ impl WorkerObjectPool {
             device.vk().create_command_pool(&info, None)
         }.unwrap();
 
+        let query_pool_info = vk::QueryPoolCreateInfo::builder()
+            .query_type(vk::QueryType::TIMESTAMP)
+            .query_count(MAX_CONCURRENT_TIMESTAMP_PASSES * 2);
+
+        let query_pool = unsafe {
+            device.vk().create_query_pool(&query_pool_info, None)
+        }.unwrap();
+
+        let timestamp_period = device.get_physical_device_properties().limits.timestamp_period;
+
         Self {
             device,
             command_pool,
             command_buffers: Vec::new(),
-            fences: Vec::new(),
+            query_pool,
+            query_slots: (0..MAX_CONCURRENT_TIMESTAMP_PASSES).map(|i| i * 2).collect(),
+            timestamp_period,
         }
     }
 
     fn get_buffer(&mut self) -> vk::CommandBuffer {
-        if self.command_buffers.is_empty() {
-            let info = vk::CommandBufferAllocateInfo::builder()
-                .command_pool(self.command_pool)
-                .level(vk::CommandBufferLevel::PRIMARY)
-                .command_buffer_count(8);
-
-            let buffers = unsafe {
-                self.device.vk().allocate_command_buffers(&info)
+        if let Some(buffer) = self.command_buffers.pop() {
+            // Reclaimed from a completed pass, reset before handing it out again.
+            unsafe {
+                self.device.vk().reset_command_buffer(buffer, vk::CommandBufferResetFlags::empty())
             }.unwrap();
 
-            self.command_buffers.extend(buffers);
+            return buffer;
         }
 
-        self.command_buffers.pop().unwrap()
+        let info = vk::CommandBufferAllocateInfo::builder()
+            .command_pool(self.command_pool)
+            .level(vk::CommandBufferLevel::PRIMARY)
+            .command_buffer_count(8);
+
+        let mut buffers = unsafe {
+            self.device.vk().allocate_command_buffers(&info)
+        }.unwrap();
+
+        let buffer = buffers.pop().unwrap();
+        self.command_buffers.extend(buffers);
+
+        buffer
     }
 
     fn return_buffer(&mut self, buffer: vk::CommandBuffer) {
@@ -172,30 +500,28 @@ impl WorkerObjectPool {
         self.command_buffers.extend_from_slice(buffers);
     }
 
-    fn get_fence(&mut self) -> vk::Fence {
-        if self.fences.is_empty() {
-            let info = vk::FenceCreateInfo::builder();
-
-            let fence = unsafe {
-                self.device.vk().create_fence(&info, None)
-            }.unwrap();
-
-            return fence;
-        }
-
-        self.fences.pop().unwrap()
+    /// Returns the base index of a pair of timestamp query slots, or [`None`] if every slot is
+    /// currently claimed by an in-flight pass.
+    fn get_query_slot(&mut self) -> Option<u32> {
+        self.query_slots.pop()
     }
 
-    fn return_fence(&mut self, fence: vk::Fence) {
-        self.fences.push(fence);
+    fn return_query_slot(&mut self, slot: u32) {
+        self.query_slots.push(slot);
     }
 }
 
+/// Number of passes each [`WorkerObjectPool`] can profile at once, each using 2 timestamp queries
+/// (start and end).
+/// Passes beyond this many concurrently in-flight simply go unprofiled rather than stalling.
+const MAX_CONCURRENT_TIMESTAMP_PASSES: u32 = 32;
+
 pub struct PooledObjectProvider {
     share: Arc<Share>,
     pool: Rc<RefCell<WorkerObjectPool>>,
     used_buffers: Vec<vk::CommandBuffer>,
-    used_fences: Vec<vk::Fence>,
+    used_query_slot: Option<u32>,
+    pending_waits: GpuFuture,
 }
 
 impl PooledObjectProvider {
@@ -204,7 +530,8 @@ impl PooledObjectProvider {
             share,
             pool,
             used_buffers: Vec::with_capacity(8),
-            used_fences: Vec::with_capacity(4),
+            used_query_slot: None,
+            pending_waits: GpuFuture::NONE,
         }
     }
 
@@ -228,21 +555,54 @@ impl PooledObjectProvider {
         Ok(cmd)
     }
 
-    pub fn get_fence(&mut self) -> vk::Fence {
-        let fence = self.pool.borrow_mut().get_fence();
-        self.used_fences.push(fence);
+    pub fn allocate_uniform<T: ToBytes>(&mut self, data: &T) -> (vk::Buffer, vk::DeviceSize) {
+        self.share.allocate_uniform(data)
+    }
 
-        fence
+    /// Declares that the pass this object belongs to must not begin executing before `future` is
+    /// reached. Composed via [`GpuFuture::join`], so an output depending on several prior passes
+    /// just widens a single wait value instead of stacking up a wait per dependency; the worker
+    /// inserts it as a wait on the timeline semaphore the next time the pass is submitted.
+    pub fn depend_on(&mut self, future: GpuFuture) {
+        self.pending_waits = self.pending_waits.join(future);
     }
 
-    pub fn allocate_uniform<T: ToBytes>(&mut self, data: &T) -> (vk::Buffer, vk::DeviceSize) {
-        self.share.allocate_uniform(data)
+    fn take_pending_waits(&mut self) -> GpuFuture {
+        std::mem::replace(&mut self.pending_waits, GpuFuture::NONE)
+    }
+
+    /// Claims the pair of timestamp query slots used to profile a single pass, if one is free.
+    /// Returns [`None`] when every slot is already claimed by an in-flight pass.
+    fn get_timestamp_query_slot(&mut self) -> Option<u32> {
+        let slot = self.pool.borrow_mut().get_query_slot();
+        self.used_query_slot = slot;
+
+        slot
+    }
+
+    fn query_pool(&self) -> vk::QueryPool {
+        self.pool.borrow().query_pool
+    }
+
+    fn timestamp_period(&self) -> f32 {
+        self.pool.borrow().timestamp_period
+    }
+
+    /// Allocates a scratch buffer of at least `size` bytes for an acceleration structure build
+    /// and returns its device address. The buffer only needs to live until the pass currently
+    /// being recorded finishes executing on the GPU, same as everything else handed out here.
+    fn allocate_scratch_buffer(&mut self, size: vk::DeviceSize) -> vk::DeviceAddress {
+        self.share.allocate_scratch_buffer(size)
     }
 }
 
 impl Drop for PooledObjectProvider {
     fn drop(&mut self) {
-        self.pool.borrow_mut().return_buffers(self.used_buffers.as_slice());
+        let mut pool = self.pool.borrow_mut();
+        pool.return_buffers(self.used_buffers.as_slice());
+        if let Some(slot) = self.used_query_slot {
+            pool.return_query_slot(slot);
+        }
     }
 }
 
@@ -269,6 +629,7 @@ impl<'a> SubmitRecorder<'a> {
 }
 
 struct PassState {
+    id: PassId,
     share: Arc<Share>,
     device: Arc<DeviceContext>,
     object_pool: PooledObjectProvider,
@@ -282,22 +643,43 @@ struct PassState {
     static_images: Vec<StaticImageId>,
     shaders: Vec<ShaderId>,
 
+    /// Acceleration structures built (or updated) for this pass, along with their bottom-level
+    /// structures. Those this pass built itself (`owned`) are destroyed in [`Drop`] alongside the
+    /// mesh/image/shader references above; refitted ones are left to their owner.
+    acceleration_structures: Vec<PassAccelerationStructureBuild>,
+
     pre_cmd: vk::CommandBuffer,
     post_cmd: vk::CommandBuffer,
 
-    end_fence: Option<vk::Fence>,
+    /// Base index of this pass' pair of timestamp queries, or [`None`] if the pool had none free
+    /// to hand out when the pass started.
+    query_slot: Option<u32>,
+
+    /// The point on the shared timeline semaphore this pass' completion is signaled at, set once
+    /// [`Self::submit`] has run.
+    completion: Option<GpuFuture>,
 }
 
 impl PassState {
-    fn new(pipeline: Arc<dyn EmulatorPipeline>, mut pass: Box<dyn EmulatorPipelinePass>, device: Arc<DeviceContext>, queue: &Queue, share: Arc<Share>, pool: Rc<RefCell<WorkerObjectPool>>, placeholder_image: vk::ImageView, placeholder_id: StaticImageId) -> Self {
+    fn new(id: PassId, pipeline: Arc<dyn EmulatorPipeline>, mut pass: Box<dyn EmulatorPipelinePass>, device: Arc<DeviceContext>, queue: &Queue, share: Arc<Share>, pool: Rc<RefCell<WorkerObjectPool>>, placeholder_image: vk::ImageView, placeholder_id: StaticImageId) -> Self {
         let mut object_pool = PooledObjectProvider::new(share.clone(), pool);
 
         let pre_cmd = object_pool.get_begin_command_buffer().unwrap();
         let post_cmd = object_pool.get_begin_command_buffer().unwrap();
 
+        let query_slot = object_pool.get_timestamp_query_slot();
+        if let Some(slot) = query_slot {
+            let query_pool = object_pool.query_pool();
+            unsafe {
+                device.vk().cmd_reset_query_pool(pre_cmd, query_pool, slot, 2);
+                device.vk().cmd_write_timestamp(pre_cmd, vk::PipelineStageFlags::TOP_OF_PIPE, query_pool, slot);
+            }
+        }
+
         pass.init(queue, &mut object_pool, placeholder_image);
 
         Self {
+            id,
             share,
             device,
             object_pool,
@@ -310,11 +692,14 @@ impl PassState {
             static_meshes: Vec::new(),
             static_images: vec![placeholder_id],
             shaders: Vec::new(),
+            acceleration_structures: Vec::new(),
 
             pre_cmd,
             post_cmd,
 
-            end_fence: None,
+            query_slot,
+
+            completion: None,
         }
     }
 
@@ -337,10 +722,109 @@ impl PassState {
         self.pass.process_task(task, &mut self.object_pool);
     }
 
+    /// Records a bottom-level acceleration structure per mesh in `task.meshes` plus a top-level
+    /// acceleration structure over them into `pre_cmd`, allocating scratch space sized from
+    /// `vkGetAccelerationStructureBuildSizesKHR` through the object pool. In
+    /// [`AccelerationStructureBuildMode::Update`] mode no bottom-level structure is built and
+    /// `task.meshes` is not used: only the top-level structure is refitted over the given `blas`.
+    fn build_acceleration_structures(&mut self, task: AccelerationStructureBuildTask) {
+        let (blas, previous_tlas, owned) = match task.mode {
+            AccelerationStructureBuildMode::Build => {
+                let blas = task.meshes.iter()
+                    .map(|mesh_id| {
+                        let geometry = self.share.get_static_mesh_geometry(*mesh_id);
+                        self.build_acceleration_structure(vk::AccelerationStructureTypeKHR::BOTTOM_LEVEL, geometry, None)
+                    })
+                    .collect();
+                (blas, None, true)
+            }
+            AccelerationStructureBuildMode::Update { blas, tlas } => (blas, Some(tlas), false),
+        };
+
+        let tlas_geometry = self.share.get_instance_geometry(&blas);
+        let tlas = self.build_acceleration_structure(vk::AccelerationStructureTypeKHR::TOP_LEVEL, tlas_geometry, previous_tlas);
+
+        self.acceleration_structures.push(PassAccelerationStructureBuild { blas, tlas, owned });
+    }
+
+    /// Builds (or, if `previous` is given, in-place updates) a single acceleration structure from
+    /// `geometry`, recording `vkCmdBuildAccelerationStructuresKHR` into `pre_cmd`.
+    fn build_acceleration_structure(&mut self, ty: vk::AccelerationStructureTypeKHR, geometry: AccelerationStructureGeometry, previous: Option<BuiltAccelerationStructure>) -> BuiltAccelerationStructure {
+        let mode = if previous.is_some() {
+            vk::BuildAccelerationStructureModeKHR::UPDATE
+        } else {
+            vk::BuildAccelerationStructureModeKHR::BUILD
+        };
+
+        let mut build_info = vk::AccelerationStructureBuildGeometryInfoKHR::builder()
+            .ty(ty)
+            .flags(vk::BuildAccelerationStructureFlagsKHR::PREFER_FAST_TRACE | vk::BuildAccelerationStructureFlagsKHR::ALLOW_UPDATE)
+            .mode(mode)
+            .geometries(std::slice::from_ref(&geometry.info));
+
+        let size_info = unsafe {
+            self.device.acceleration_structure_khr().get_acceleration_structure_build_sizes(
+                vk::AccelerationStructureBuildTypeKHR::DEVICE,
+                &build_info,
+                std::slice::from_ref(&geometry.range.primitive_count),
+            )
+        };
+
+        let acceleration_structure = match previous {
+            Some(existing) => existing,
+            None => {
+                let buffer = self.share.allocate_acceleration_structure_buffer(size_info.acceleration_structure_size);
+                let handle = unsafe {
+                    self.device.acceleration_structure_khr().create_acceleration_structure(
+                        &vk::AccelerationStructureCreateInfoKHR::builder()
+                            .ty(ty)
+                            .buffer(buffer)
+                            .size(size_info.acceleration_structure_size),
+                        None,
+                    )
+                }.unwrap();
+
+                BuiltAccelerationStructure { acceleration_structure: handle, buffer }
+            }
+        };
+
+        let scratch_size = if mode == vk::BuildAccelerationStructureModeKHR::UPDATE {
+            size_info.update_scratch_size
+        } else {
+            size_info.build_scratch_size
+        };
+        let scratch_address = self.object_pool.allocate_scratch_buffer(scratch_size);
+
+        build_info = build_info
+            .dst_acceleration_structure(acceleration_structure.acceleration_structure)
+            .src_acceleration_structure(if mode == vk::BuildAccelerationStructureModeKHR::UPDATE {
+                acceleration_structure.acceleration_structure
+            } else {
+                vk::AccelerationStructureKHR::null()
+            })
+            .scratch_data(vk::DeviceOrHostAddressKHR { device_address: scratch_address });
+
+        unsafe {
+            self.device.acceleration_structure_khr().cmd_build_acceleration_structures(
+                self.pre_cmd,
+                std::slice::from_ref(&build_info),
+                &[std::slice::from_ref(&geometry.range)],
+            );
+        }
+
+        acceleration_structure
+    }
+
     fn submit(&mut self, queue: &Queue) {
-        assert!(self.end_fence.is_none());
-        let end_fence = self.object_pool.get_fence();
-        self.end_fence = Some(end_fence);
+        assert!(self.completion.is_none());
+        let signal_value = self.share.next_timeline_value();
+        self.completion = Some(GpuFuture(signal_value));
+
+        if let Some(slot) = self.query_slot {
+            unsafe {
+                self.device.vk().cmd_write_timestamp(self.post_cmd, vk::PipelineStageFlags::BOTTOM_OF_PIPE, self.object_pool.query_pool(), slot + 1);
+            }
+        }
 
         unsafe {
             self.device.vk().end_command_buffer(self.pre_cmd)
@@ -353,15 +837,16 @@ impl PassState {
         let submit_alloc = Bump::new();
         let mut submit_recorder = SubmitRecorder::new(32);
 
-        self.record_pre_submits(&mut submit_recorder, &submit_alloc);
+        let wait_for = self.object_pool.take_pending_waits();
+        self.record_pre_submits(&mut submit_recorder, &submit_alloc, wait_for);
         self.pass.record(&mut self.object_pool, &mut submit_recorder, &submit_alloc);
         for output in &mut self.outputs {
             output.record(&mut self.object_pool, &mut submit_recorder, &submit_alloc);
         }
-        self.record_post_submits(&mut submit_recorder, &submit_alloc);
+        self.record_post_submits(&mut submit_recorder, &submit_alloc, signal_value);
 
         unsafe {
-            queue.submit_2(submit_recorder.as_slice(), Some(end_fence))
+            queue.submit_2(submit_recorder.as_slice(), None)
         }.unwrap();
 
         for output in &mut self.outputs {
@@ -369,30 +854,73 @@ impl PassState {
         }
     }
 
-    fn is_complete(&self) -> bool {
-        if let Some(fence) = self.end_fence {
-            unsafe {
-                self.device.vk().get_fence_status(fence)
-            }.unwrap()
-        } else {
-            panic!("Illegal state");
-        }
+    /// The point on the timeline semaphore at which this pass' GPU work is guaranteed to have
+    /// finished. Only valid to call once [`Self::submit`] has run.
+    fn completion(&self) -> GpuFuture {
+        self.completion.expect("PassState::completion called before submit")
     }
 
-    fn record_pre_submits<'a>(&self, recorder: &mut SubmitRecorder<'a>, alloc: &'a Bump) {
+    fn record_pre_submits<'a>(&self, recorder: &mut SubmitRecorder<'a>, alloc: &'a Bump, wait_for: GpuFuture) {
         let cmd_infos = alloc.alloc([
             vk::CommandBufferSubmitInfo::builder()
                 .command_buffer(self.pre_cmd)
                 .build()
         ]);
 
-        let submit_info = vk::SubmitInfo2::builder()
+        let mut submit_info = vk::SubmitInfo2::builder()
             .command_buffer_infos(cmd_infos);
 
+        if wait_for != GpuFuture::NONE {
+            let wait_infos = alloc.alloc([
+                vk::SemaphoreSubmitInfo::builder()
+                    .semaphore(self.share.timeline_semaphore())
+                    .value(wait_for.0)
+                    .stage_mask(vk::PipelineStageFlags2::ALL_COMMANDS)
+                    .build()
+            ]);
+            submit_info = submit_info.wait_semaphore_infos(wait_infos);
+        }
+
         recorder.push(submit_info);
     }
 
-    fn record_post_submits<'a>(&self, _: &mut SubmitRecorder<'a>, _: &'a Bump) {
+    fn record_post_submits<'a>(&self, recorder: &mut SubmitRecorder<'a>, alloc: &'a Bump, signal_value: u64) {
+        let cmd_infos = alloc.alloc([
+            vk::CommandBufferSubmitInfo::builder()
+                .command_buffer(self.post_cmd)
+                .build()
+        ]);
+
+        let signal_infos = alloc.alloc([
+            vk::SemaphoreSubmitInfo::builder()
+                .semaphore(self.share.timeline_semaphore())
+                .value(signal_value)
+                .stage_mask(vk::PipelineStageFlags2::ALL_COMMANDS)
+                .build()
+        ]);
+
+        let submit_info = vk::SubmitInfo2::builder()
+            .command_buffer_infos(cmd_infos)
+            .signal_semaphore_infos(signal_infos);
+
+        recorder.push(submit_info);
+    }
+
+    /// Reads back this pass' GPU duration, if it was assigned a pair of timestamp query slots.
+    /// Must only be called once this pass' [`Self::completion`] has been reached, so the query
+    /// results are already available and the read never stalls the worker thread.
+    fn resolve_gpu_time(&self) -> Option<Duration> {
+        let slot = self.query_slot?;
+
+        let mut timestamps = [0u64; 2];
+        unsafe {
+            self.device.vk().get_query_pool_results(self.object_pool.query_pool(), slot, &mut timestamps, vk::QueryResultFlags::TYPE_64)
+        }.unwrap();
+
+        let ticks = timestamps[1].saturating_sub(timestamps[0]);
+        let nanos = (ticks as f64) * (self.object_pool.timestamp_period() as f64);
+
+        Some(Duration::from_nanos(nanos as u64))
     }
 }
 
@@ -410,5 +938,19 @@ impl Drop for PassState {
         for shader in &self.shaders {
             self.pipeline.dec_shader_used(*shader);
         }
+        for build in self.acceleration_structures.drain(..) {
+            if !build.owned {
+                // Refitted in place from a structure the caller owns and is reusing across
+                // passes; it outlives this pass.
+                continue;
+            }
+
+            for built in build.blas.into_iter().chain(std::iter::once(build.tlas)) {
+                unsafe {
+                    self.device.acceleration_structure_khr().destroy_acceleration_structure(built.acceleration_structure, None);
+                    self.device.vk().destroy_buffer(built.buffer, None);
+                }
+            }
+        }
     }
-}
\ No newline at end of file
+}